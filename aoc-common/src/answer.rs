@@ -0,0 +1,92 @@
+use std::fmt::{self, Display};
+
+use serde::Serialize;
+
+/// A puzzle answer: almost always a number, occasionally some text.
+///
+/// Numbers serialise as JSON numbers and text as JSON strings.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Int(i128),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Text(s) => f.write_str(s),
+        }
+    }
+}
+
+macro_rules! from_int {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(n: $ty) -> Self {
+                    Answer::Int(n.into())
+                }
+            }
+        )*
+    };
+}
+
+from_int!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+impl From<isize> for Answer {
+    fn from(n: isize) -> Self {
+        Answer::Int(n as i128)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::Int(n as i128)
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Self {
+        i128::try_from(n)
+            .map(Answer::Int)
+            .unwrap_or_else(|_| Answer::Text(n.to_string()))
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn answers_serialise_by_kind() {
+        assert_eq!(
+            serde_json::to_string(&Answer::from(10637009915279u64)).unwrap(),
+            "10637009915279"
+        );
+        assert_eq!(serde_json::to_string(&Answer::from(-3i32)).unwrap(), "-3");
+        assert_eq!(
+            serde_json::to_string(&Answer::from("PCPHARKL")).unwrap(),
+            "\"PCPHARKL\""
+        );
+    }
+
+    #[test]
+    fn huge_u128_falls_back_to_text() {
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert!(matches!(Answer::from(u128::MAX), Answer::Text(_)));
+    }
+}
@@ -0,0 +1,18 @@
+/// Shifts `memo` left by one and appends `bit`, so that folding a big-endian
+/// sequence of bits yields the number they spell.
+pub fn fold_bit(mut memo: usize, bit: bool) -> usize {
+    memo <<= 1;
+    memo += bit as usize;
+    memo
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn fold_bit_works() {
+        let bits = "000100010".chars().map(|c| matches!(c, '1'));
+        assert_eq!(bits.fold(0, fold_bit), 34)
+    }
+}
@@ -0,0 +1,282 @@
+use std::ops::{Index, IndexMut};
+
+use anyhow::{anyhow, bail, Result};
+
+use crate::ParseError;
+
+/// An `(x, y)` position in a [`Grid`], with `(0, 0)` at the top left.
+pub type Coord = (usize, usize);
+
+/// Orthogonal neighbour offsets, in reading order.
+pub const OFFSETS4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Orthogonal and diagonal neighbour offsets, in reading order.
+pub const OFFSETS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// The 3x3 window centred on a cell, including the cell itself, in reading order.
+pub const WINDOW: [(isize, isize); 9] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (0, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A dense, row-major 2D grid.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Result<Self> {
+        if cells.len() != width * height {
+            bail!(
+                "expected {} cells for a {}x{} grid, got {}",
+                width * height,
+                width,
+                height,
+                cells.len()
+            );
+        }
+
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Builds a grid from rows, which must all be the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let height = rows.len();
+        let width = rows.first().map(|row| row.len()).unwrap_or(0);
+        let mut cells = Vec::with_capacity(width * height);
+
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                bail!("row {} has length {}, expected {}", y, row.len(), width);
+            }
+            cells.extend(row);
+        }
+
+        Self::new(width, height, cells)
+    }
+
+    /// Parses one cell per character, one row per line.
+    pub fn parse_with(input: &str, mut f: impl FnMut(char) -> Result<T>) -> Result<Self> {
+        let rows = input
+            .lines()
+            .enumerate()
+            .map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(x, c)| {
+                        f(c).map_err(|e| {
+                            ParseError::new(y + 1, x + 1, format!("invalid cell {:?}: {:#}", c, e))
+                                .into()
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()
+            })
+            .collect::<Result<Vec<Vec<_>>>>()?;
+
+        let width = rows.first().map(|row| row.len()).unwrap_or(0);
+        if let Some((y, row)) = rows.iter().enumerate().find(|(_, row)| row.len() != width) {
+            return Err(ParseError::new(
+                y + 1,
+                row.len().min(width) + 1,
+                format!("expected {} cells, found {}", width, row.len()),
+            )
+            .into());
+        }
+
+        Self::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): Coord) -> Option<&T> {
+        (x < self.width && y < self.height).then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, (x, y): Coord) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Moves `coord` by `offset`, returning `None` if that leaves the grid.
+    pub fn offset(&self, coord: Coord, offset: (isize, isize)) -> Option<Coord> {
+        offset_within(self.width, self.height, coord, offset)
+    }
+
+    /// The in-bounds orthogonal neighbours of `coord`.
+    pub fn neighbours4(&self, coord: Coord) -> impl Iterator<Item = Coord> {
+        let (width, height) = (self.width, self.height);
+        OFFSETS4
+            .iter()
+            .filter_map(move |offset| offset_within(width, height, coord, *offset))
+    }
+
+    /// The in-bounds orthogonal and diagonal neighbours of `coord`.
+    pub fn neighbours8(&self, coord: Coord) -> impl Iterator<Item = Coord> {
+        let (width, height) = (self.width, self.height);
+        OFFSETS8
+            .iter()
+            .filter_map(move |offset| offset_within(width, height, coord, *offset))
+    }
+
+    /// Every coordinate in the grid, in reading order.
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let (width, height) = (self.width, self.height);
+        (0..height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell alongside its coordinate, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.coords().zip(self.cells.iter())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+}
+
+impl Grid<u8> {
+    /// Parses a grid of single decimal digits such as `"123\n456"`.
+    pub fn parse_digits(input: &str) -> Result<Self> {
+        Self::parse_with(input, |c| {
+            c.to_digit(10)
+                .map(|d| d as u8)
+                .ok_or_else(|| anyhow!("not a digit"))
+        })
+    }
+}
+
+fn offset_within(
+    width: usize,
+    height: usize,
+    (x, y): Coord,
+    (dx, dy): (isize, isize),
+) -> Option<Coord> {
+    let x = x.checked_add_signed(dx)?;
+    let y = y.checked_add_signed(dy)?;
+
+    (x < width && y < height).then_some((x, y))
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &Self::Output {
+        self.get(coord)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", coord))
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut Self::Output {
+        self.get_mut(coord)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", coord))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_digits_works() {
+        let grid = Grid::parse_digits("123\n456").unwrap();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[(0, 0)], 1);
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get((3, 0)), None);
+    }
+
+    #[test]
+    fn parse_digits_reports_position() {
+        let err = Grid::parse_digits("123\n4x6").unwrap_err();
+        assert_eq!(
+            err.downcast::<ParseError>().unwrap(),
+            ParseError::new(2, 2, "invalid cell 'x': not a digit")
+        );
+    }
+
+    #[test]
+    fn ragged_rows_are_rejected() {
+        let err = Grid::parse_digits("123\n45").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: expected 3 cells, found 2"
+        );
+    }
+
+    #[test]
+    fn neighbours_are_bounds_checked() {
+        let grid = Grid::filled(3, 3, 0);
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+        assert_eq!(
+            grid.neighbours8((2, 2)).collect::<Vec<_>>(),
+            vec![(1, 1), (2, 1), (1, 2)]
+        );
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn rows_works() {
+        let grid = Grid::parse_digits("12\n34").unwrap();
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&[1, 2][..], &[3, 4][..]]
+        );
+    }
+}
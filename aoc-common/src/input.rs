@@ -0,0 +1,29 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::Path,
+};
+
+use anyhow::{Context, Result};
+
+/// Reads the puzzle input named by the first command line argument, or stdin
+/// when there is no argument or it is `-`.
+pub fn read_input() -> Result<String> {
+    match std::env::args_os().nth(1) {
+        Some(path) => read(Path::new(&path)),
+        None => read(Path::new("-")),
+    }
+}
+
+/// Reads the puzzle input at `path`, where `-` means stdin.
+pub fn read(path: &Path) -> Result<String> {
+    if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .context("couldn't read stdin")?;
+        Ok(input)
+    } else {
+        fs::read_to_string(path).with_context(|| format!("couldn't read {}", path.display()))
+    }
+}
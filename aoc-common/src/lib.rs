@@ -0,0 +1,13 @@
+pub mod answer;
+pub mod bits;
+pub mod grid;
+pub mod input;
+pub mod ocr;
+pub mod parse;
+pub mod solution;
+
+pub use answer::Answer;
+pub use grid::{Coord, Grid};
+pub use input::read_input;
+pub use parse::ParseError;
+pub use solution::{solve, DynSolution, PartAnswer, Solution};
@@ -0,0 +1,90 @@
+use anyhow::{anyhow, bail, Result};
+
+const LETTER_WIDTH: usize = 4;
+const LETTER_HEIGHT: usize = 6;
+
+/// The 4x6 capital letters the puzzles draw with, one row per line.
+const LETTERS: &[(char, [&str; LETTER_HEIGHT])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Reads the letters out of a picture of lit (`true`) cells, where each letter
+/// is 4 cells wide and followed by a one cell gap.
+pub fn read(picture: &[Vec<bool>]) -> Result<String> {
+    if picture.len() != LETTER_HEIGHT {
+        bail!(
+            "letters are {} rows tall, the picture has {} rows",
+            LETTER_HEIGHT,
+            picture.len()
+        );
+    }
+
+    let width = picture.iter().map(|row| row.len()).max().unwrap_or(0);
+    let lit = |x: usize, y: usize| picture[y].get(x).copied().unwrap_or(false);
+
+    (0..width)
+        .step_by(LETTER_WIDTH + 1)
+        .map(|left| {
+            LETTERS
+                .iter()
+                .find(|(_, rows)| {
+                    rows.iter().enumerate().all(|(y, row)| {
+                        row.chars()
+                            .enumerate()
+                            .all(|(x, c)| (c == '#') == lit(left + x, y))
+                    })
+                })
+                .map(|(letter, _)| *letter)
+                .ok_or_else(|| anyhow!("unknown letter at column {}", left + 1))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn picture(rows: &[&str]) -> Vec<Vec<bool>> {
+        rows.iter()
+            .map(|row| row.chars().map(|c| c == '#').collect())
+            .collect()
+    }
+
+    #[test]
+    fn read_works() {
+        let hi = picture(&[
+            "#..#..###",
+            "#..#...#.",
+            "####...#.",
+            "#..#...#.",
+            "#..#...#.",
+            "#..#..###",
+        ]);
+        assert_eq!(read(&hi).unwrap(), "HI");
+    }
+
+    #[test]
+    fn unknown_letters_are_rejected() {
+        let blob = picture(&["####"; 6]);
+        assert_eq!(
+            read(&blob).unwrap_err().to_string(),
+            "unknown letter at column 1"
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use std::fmt::{self, Display};
+
+use anyhow::{Error, Result};
+
+/// Malformed puzzle input, with the 1-based line and column it was found at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Display) -> Self {
+        Self {
+            line,
+            column,
+            message: message.to_string(),
+        }
+    }
+
+    /// An error about `part`, which must be a slice of `src`, positioned where `part` starts.
+    pub fn at(src: &str, part: &str, message: impl Display) -> Self {
+        let (line, column) = position(src, part);
+        Self::new(line, column, message)
+    }
+
+    /// Moves an error positioned relative to `part`, a slice of `src`, to the
+    /// same place relative to `src`.
+    pub fn relative_to(self, src: &str, part: &str) -> Self {
+        let (line, column) = position(src, part);
+
+        if self.line == 1 {
+            Self::new(line, column + self.column - 1, self.message)
+        } else {
+            Self::new(line + self.line - 1, self.column, self.message)
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Runs `f` on `part`, a slice of `src`, and makes any error it returns point into `src`.
+///
+/// A [`ParseError`] relative to `part` is moved to the same place in `src`; any
+/// other error becomes a [`ParseError`] at the start of `part`.
+pub fn within<'a, T>(src: &str, part: &'a str, f: impl FnOnce(&'a str) -> Result<T>) -> Result<T> {
+    f(part).map_err(|e| Error::new(relocate(src, part, e)))
+}
+
+/// Parses every line of `src` with `f`, pointing any error at its line.
+pub fn parse_lines<'a, T>(src: &'a str, mut f: impl FnMut(&'a str) -> Result<T>) -> Result<Vec<T>> {
+    src.lines().map(|line| within(src, line, &mut f)).collect()
+}
+
+fn relocate(src: &str, part: &str, e: Error) -> ParseError {
+    match e.downcast::<ParseError>() {
+        Ok(e) => e.relative_to(src, part),
+        Err(e) => ParseError::at(src, part, format!("{:#}", e)),
+    }
+}
+
+/// The 1-based line and column at which `part`, a slice of `src`, starts.
+fn position(src: &str, part: &str) -> (usize, usize) {
+    let offset = (part.as_ptr() as usize)
+        .checked_sub(src.as_ptr() as usize)
+        .filter(|offset| *offset <= src.len())
+        .unwrap_or(0);
+    let before = &src[..offset];
+    let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use anyhow::bail;
+
+    use super::*;
+
+    fn parse_num(s: &str) -> Result<u32> {
+        match s.split_once('=') {
+            Some((_, n)) => n
+                .parse()
+                .map_err(|_| ParseError::at(s, n, format!("{:?} is not a number", n)).into()),
+            None => bail!("missing `=`"),
+        }
+    }
+
+    #[test]
+    fn parse_lines_points_at_the_bad_part() {
+        let err = parse_lines("a=1\nb=2\nccc=x", parse_num).unwrap_err();
+        assert_eq!(
+            err.downcast::<ParseError>().unwrap(),
+            ParseError::new(3, 5, "\"x\" is not a number")
+        );
+    }
+
+    #[test]
+    fn other_errors_point_at_the_line() {
+        let err = parse_lines("a=1\nb2", parse_num).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 1: missing `=`");
+    }
+
+    #[test]
+    fn within_nests() {
+        let src = "header\n\nx=1\ny=z";
+        let (_, body) = src.split_once("\n\n").unwrap();
+        let err = within(src, body, |body| parse_lines(body, parse_num)).unwrap_err();
+        assert_eq!(err.to_string(), "line 4, column 3: \"z\" is not a number");
+    }
+}
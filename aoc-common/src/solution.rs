@@ -0,0 +1,90 @@
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Result};
+
+use crate::{input::read_input, Answer};
+
+/// A day's puzzle: parse the input once, then solve each part from it.
+pub trait Solution {
+    type Input;
+    type Part1: Into<Answer>;
+    type Part2: Into<Answer>;
+
+    /// Day 25 only has one part.
+    const HAS_PART2: bool = true;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Result<Self::Part1>;
+    fn part2(input: &Self::Input) -> Result<Self::Part2>;
+}
+
+/// The outcome of solving one part, and how long solving it took (parsing excluded).
+#[derive(Debug)]
+pub struct PartAnswer {
+    pub part: u8,
+    pub answer: Result<Answer>,
+    pub elapsed: Duration,
+}
+
+/// An object-safe view of a [`Solution`], so days can be stored side by side.
+pub trait DynSolution: Sync {
+    fn parts(&self) -> &'static [u8];
+
+    /// Parses `input` once and solves each requested part, returning the answers in order.
+    fn run(&self, input: &str, parts: &[u8]) -> Result<Vec<PartAnswer>>;
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution + Sync,
+{
+    fn parts(&self) -> &'static [u8] {
+        if S::HAS_PART2 {
+            &[1, 2]
+        } else {
+            &[1]
+        }
+    }
+
+    fn run(&self, input: &str, parts: &[u8]) -> Result<Vec<PartAnswer>> {
+        let input = S::parse(input)?;
+
+        Ok(parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let answer = match part {
+                    1 => S::part1(&input).map(Into::into),
+                    2 if S::HAS_PART2 => S::part2(&input).map(Into::into),
+                    _ => Err(anyhow!("there is no part {}", part)),
+                };
+                PartAnswer {
+                    part,
+                    answer,
+                    elapsed: start.elapsed(),
+                }
+            })
+            .collect())
+    }
+}
+
+/// The body of each day's own binary: solves every part of `S` on the input
+/// from [`read_input`] and prints one `part N: answer` line per part.
+pub fn solve<S: Solution>() -> Result<()> {
+    let input = S::parse(&read_input()?)?;
+
+    print_answer(1, S::part1(&input)?.into());
+    if S::HAS_PART2 {
+        print_answer(2, S::part2(&input)?.into());
+    }
+    Ok(())
+}
+
+fn print_answer(part: u8, answer: Answer) {
+    match answer {
+        Answer::Text(text) if text.contains('\n') => {
+            println!("part {}:\n{}", part, text.trim_end())
+        }
+        answer => println!("part {}: {}", part, answer),
+    }
+}
@@ -0,0 +1,72 @@
+//! Times parsing, part 1 and part 2 separately for every day on its real input.
+//!
+//! Save a baseline before a rewrite and compare against it afterwards:
+//!
+//! ```text
+//! cargo bench -p aoc --bench days -- --save-baseline before day12
+//! cargo bench -p aoc --bench days -- --baseline before day12
+//! ```
+
+use std::hint::black_box;
+
+use aoc::default_input_path;
+use aoc_common::Solution;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+/// Days whose slowest part takes more than ~100ms, which only get the minimum
+/// number of samples so a full run stays bearable.
+const SLOW_DAYS: &[u8] = &[12, 15, 18, 20, 25];
+
+/// Skips days whose real input isn't checked in.
+fn bench_day<S: Solution>(c: &mut Criterion, day: u8) {
+    let path = default_input_path(day);
+    if !path.exists() {
+        return;
+    }
+    let raw = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("couldn't read {}: {}", path.display(), e));
+    let input = S::parse(&raw).unwrap();
+
+    let mut group = c.benchmark_group(format!("day{}", day));
+    if SLOW_DAYS.contains(&day) {
+        group.sample_size(10);
+    }
+
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&raw)).unwrap()));
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&input)).unwrap()));
+    if S::HAS_PART2 {
+        group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&input)).unwrap()));
+    }
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<day1::Day1>(c, 1);
+    bench_day::<day2::Day2>(c, 2);
+    bench_day::<day3::Day3>(c, 3);
+    bench_day::<day4::Day4>(c, 4);
+    bench_day::<day5::Day5>(c, 5);
+    bench_day::<day6::Day6>(c, 6);
+    bench_day::<day7::Day7>(c, 7);
+    bench_day::<day8::Day8>(c, 8);
+    bench_day::<day9::Day9>(c, 9);
+    bench_day::<day10::Day10>(c, 10);
+    bench_day::<day11::Day11>(c, 11);
+    bench_day::<day12::Day12>(c, 12);
+    bench_day::<day13::Day13>(c, 13);
+    bench_day::<day14::Day14>(c, 14);
+    bench_day::<day15::Day15>(c, 15);
+    bench_day::<day16::Day16>(c, 16);
+    bench_day::<day17::Day17>(c, 17);
+    bench_day::<day18::Day18>(c, 18);
+    bench_day::<day19::Day19>(c, 19);
+    bench_day::<day20::Day20>(c, 20);
+    bench_day::<day21::Day21>(c, 21);
+    bench_day::<day22::Day22>(c, 22);
+    bench_day::<day23::Day23>(c, 23);
+    bench_day::<day24::Day24>(c, 24);
+    bench_day::<day25::Day25>(c, 25);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,97 @@
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+
+/// Where a day's recorded answers for its real input live.
+pub fn default_answers_path(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day{}", day))
+        .join("answers.txt")
+}
+
+/// The recorded answers for one day, by part.
+///
+/// The file holds one `part N: answer` line per part, which is exactly what the
+/// day's own binary prints, so it can be regenerated with
+/// `cargo run --release -p dayN > dayN/answers.txt`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers(BTreeMap<u8, String>);
+
+impl Answers {
+    /// Reads the answers at `path`; a missing file means no answers are recorded yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(s) => Self::parse(&s).with_context(|| format!("in {}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e).with_context(|| format!("couldn't read {}", path.display())),
+        }
+    }
+
+    pub fn parse(s: &str) -> Result<Self> {
+        let mut answers = BTreeMap::new();
+
+        for (i, line) in s.lines().enumerate().filter(|(_, l)| !l.trim().is_empty()) {
+            let (part, answer) = line
+                .strip_prefix("part ")
+                .and_then(|line| line.split_once(": "))
+                .with_context(|| format!("line {}: expected `part N: answer`", i + 1))?;
+            let part = part
+                .parse::<u8>()
+                .with_context(|| format!("line {}: {:?} is not a part", i + 1, part))?;
+
+            if answers.insert(part, answer.to_owned()).is_some() {
+                bail!("line {}: part {} is recorded twice", i + 1, part);
+            }
+        }
+
+        Ok(Self(answers))
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        self.0.get(&part).map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_works() {
+        let answers = Answers::parse("part 1: 671\npart 2: PCPHARKL\n").unwrap();
+        assert_eq!(answers.get(1), Some("671"));
+        assert_eq!(answers.get(2), Some("PCPHARKL"));
+        assert_eq!(answers.get(3), None);
+    }
+
+    #[test]
+    fn malformed_lines_are_rejected() {
+        let err = Answers::parse("part 1: 1\n1676\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 2: expected `part N: answer`");
+        assert!(Answers::parse("part 1: 1\npart 1: 2").is_err());
+    }
+
+    #[test]
+    fn every_solved_day_has_answers() {
+        // a day whose real input isn't checked in has nothing to record
+        for (day, solution) in crate::DAYS {
+            if !crate::default_input_path(*day).exists() {
+                continue;
+            }
+            let answers = Answers::load(&default_answers_path(*day)).unwrap();
+            for part in solution.parts() {
+                assert!(
+                    answers.get(*part).is_some(),
+                    "day {} part {} has no recorded answer",
+                    day,
+                    part
+                );
+            }
+        }
+    }
+}
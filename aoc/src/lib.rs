@@ -0,0 +1,121 @@
+use std::{
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use anyhow::{bail, Context, Error, Result};
+use aoc_common::DynSolution;
+
+pub mod answers;
+
+pub const DAYS: &[(u8, &dyn DynSolution)] = &[
+    (1, &day1::Day1),
+    (2, &day2::Day2),
+    (3, &day3::Day3),
+    (4, &day4::Day4),
+    (5, &day5::Day5),
+    (6, &day6::Day6),
+    (7, &day7::Day7),
+    (8, &day8::Day8),
+    (9, &day9::Day9),
+    (10, &day10::Day10),
+    (11, &day11::Day11),
+    (12, &day12::Day12),
+    (13, &day13::Day13),
+    (14, &day14::Day14),
+    (15, &day15::Day15),
+    (16, &day16::Day16),
+    (17, &day17::Day17),
+    (18, &day18::Day18),
+    (19, &day19::Day19),
+    (20, &day20::Day20),
+    (21, &day21::Day21),
+    (22, &day22::Day22),
+    (23, &day23::Day23),
+    (24, &day24::Day24),
+    (25, &day25::Day25),
+];
+
+/// Which days to run, from the `--day` argument: `16`, `3-7` or `all`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySelection(RangeInclusive<u8>);
+
+impl DaySelection {
+    pub fn is_single(&self) -> bool {
+        self.0.start() == self.0.end()
+    }
+
+    /// The solved days in this selection, in order.
+    pub fn days(&self) -> impl Iterator<Item = (u8, &'static dyn DynSolution)> + '_ {
+        DAYS.iter()
+            .filter(|(day, _)| self.0.contains(day))
+            .map(|(day, solution)| (*day, *solution))
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        fn parse_day(s: &str) -> Result<u8> {
+            let day = s
+                .trim()
+                .parse::<u8>()
+                .with_context(|| format!("{:?} is not a day", s))?;
+            if !(1..=25).contains(&day) {
+                bail!("day {} is outside 1-25", day);
+            }
+            Ok(day)
+        }
+
+        if s == "all" {
+            return Ok(Self(1..=25));
+        }
+
+        let range = match s.split_once('-') {
+            Some((start, end)) => parse_day(start)?..=parse_day(end)?,
+            None => {
+                let day = parse_day(s)?;
+                day..=day
+            }
+        };
+
+        if range.is_empty() {
+            bail!("{:?} is an empty range of days", s);
+        }
+        Ok(Self(range))
+    }
+}
+
+/// Where a day's puzzle input lives when no `--input` is given.
+pub fn default_input_path(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day{}", day))
+        .join("input.txt")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn day_selection_parses() {
+        assert_eq!("16".parse::<DaySelection>().unwrap(), DaySelection(16..=16));
+        assert_eq!("3-7".parse::<DaySelection>().unwrap(), DaySelection(3..=7));
+        assert_eq!("all".parse::<DaySelection>().unwrap(), DaySelection(1..=25));
+        assert!("0".parse::<DaySelection>().is_err());
+        assert!("26".parse::<DaySelection>().is_err());
+        assert!("7-3".parse::<DaySelection>().is_err());
+        assert!("x".parse::<DaySelection>().is_err());
+    }
+
+    #[test]
+    fn day_selection_lists_days_in_order() {
+        let selection = "21-25".parse::<DaySelection>().unwrap();
+        let days = selection.days().map(|(day, _)| day).collect::<Vec<_>>();
+        assert_eq!(days, vec![21, 22, 23, 24, 25]);
+        assert_eq!(DaySelection(1..=25).days().count(), 25);
+    }
+}
@@ -0,0 +1,237 @@
+use std::{path::PathBuf, process::ExitCode};
+
+use anyhow::{bail, Result};
+use aoc::{
+    answers::{default_answers_path, Answers},
+    default_input_path, DaySelection,
+};
+use aoc_common::{Answer, PartAnswer};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code 2021 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, a range of days, or all of them; in a range, days without
+    /// a `dayN/input.txt` are skipped
+    Run(RunArgs),
+    /// Solve days against their real input and compare with `dayN/answers.txt`;
+    /// in a range, days without a `dayN/input.txt` are skipped
+    Verify(VerifyArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// A single day (`16`), an inclusive range (`3-7`) or `all`
+    #[arg(long, default_value = "all")]
+    day: DaySelection,
+
+    /// Only solve this part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Read the puzzle input from this file (or `-` for stdin) instead of `dayN/input.txt`;
+    /// needs a single `--day`
+    #[arg(long)]
+    input: Option<PathBuf>,
+
+    /// How to print the answers
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// A single day (`16`), an inclusive range (`3-7`) or `all`
+    #[arg(long, default_value = "all")]
+    day: DaySelection,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// `day 16 part 2: 10637009915279`
+    Text,
+    /// One JSON object per part: `{"day":16,"part":2,"answer":10637009915279,"elapsed_ns":1234}`
+    Json,
+}
+
+#[derive(Serialize)]
+struct JsonAnswer<'a> {
+    day: u8,
+    part: u8,
+    answer: &'a Answer,
+    elapsed_ns: u128,
+}
+
+fn print_answer(
+    format: Format,
+    day: u8,
+    part: u8,
+    answer: &Answer,
+    elapsed_ns: u128,
+) -> Result<()> {
+    match format {
+        Format::Text => match answer {
+            Answer::Text(text) if text.contains('\n') => {
+                println!("day {} part {}:\n{}", day, part, text.trim_end())
+            }
+            answer => println!("day {} part {}: {}", day, part, answer),
+        },
+        Format::Json => {
+            let line = serde_json::to_string(&JsonAnswer {
+                day,
+                part,
+                answer,
+                elapsed_ns,
+            })?;
+            println!("{}", line);
+        }
+    }
+    Ok(())
+}
+
+fn run(args: &RunArgs) -> Result<bool> {
+    if args.input.is_some() && !args.day.is_single() {
+        bail!("--input can only be used with a single --day");
+    }
+    if args.day.days().next().is_none() {
+        bail!("there are no solutions for the selected days");
+    }
+
+    let mut ok = true;
+    for (day, solution) in args.day.days() {
+        let path = args
+            .input
+            .clone()
+            .unwrap_or_else(|| default_input_path(day));
+        if args.input.is_none() && !args.day.is_single() && !path.exists() {
+            eprintln!(
+                "day {}: skipped: there is no input at {}",
+                day,
+                path.display()
+            );
+            continue;
+        }
+        let result = aoc_common::input::read(&path).and_then(|input| {
+            let parts = match args.part {
+                Some(part) => vec![part],
+                None => solution.parts().to_vec(),
+            };
+            solution.run(&input, &parts)
+        });
+
+        let answers = match result {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("day {}: {:#}", day, e);
+                ok = false;
+                continue;
+            }
+        };
+
+        for PartAnswer {
+            part,
+            answer,
+            elapsed,
+        } in answers
+        {
+            match answer {
+                Ok(answer) => print_answer(args.format, day, part, &answer, elapsed.as_nanos())?,
+                Err(e) => {
+                    eprintln!("day {} part {}: {:#}", day, part, e);
+                    ok = false;
+                }
+            }
+        }
+    }
+
+    Ok(ok)
+}
+
+fn verify(args: &VerifyArgs) -> Result<bool> {
+    if args.day.days().next().is_none() {
+        bail!("there are no solutions for the selected days");
+    }
+
+    let mut ok = true;
+    for (day, solution) in args.day.days() {
+        let path = default_input_path(day);
+        if !args.day.is_single() && !path.exists() {
+            println!(
+                "day {}: skipped: there is no input at {}",
+                day,
+                path.display()
+            );
+            continue;
+        }
+        let result = Answers::load(&default_answers_path(day)).and_then(|expected| {
+            let input = aoc_common::input::read(&path)?;
+            Ok((expected, solution.run(&input, solution.parts())?))
+        });
+
+        let (expected, answers) = match result {
+            Ok(result) => result,
+            Err(e) => {
+                eprintln!("day {}: {:#}", day, e);
+                ok = false;
+                continue;
+            }
+        };
+
+        for PartAnswer { part, answer, .. } in answers {
+            let answer = match answer {
+                Ok(answer) => answer.to_string(),
+                Err(e) => {
+                    println!("day {} part {}: error: {:#}", day, part, e);
+                    ok = false;
+                    continue;
+                }
+            };
+
+            match expected.get(part) {
+                Some(expected) if expected == answer => {
+                    println!("day {} part {}: ok", day, part)
+                }
+                Some(expected) => {
+                    println!(
+                        "day {} part {}: mismatch: expected {}, got {}",
+                        day, part, expected, answer
+                    );
+                    ok = false;
+                }
+                None => {
+                    println!(
+                        "day {} part {}: missing: no recorded answer, got {}",
+                        day, part, answer
+                    );
+                    ok = false;
+                }
+            }
+        }
+    }
+
+    Ok(ok)
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match &cli.command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify(args),
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("{:#}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
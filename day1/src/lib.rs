@@ -0,0 +1,49 @@
+use anyhow::{Context, Result};
+use aoc_common::{parse::parse_lines, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<i32>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_lines(input, |line| {
+            line.parse::<i32>()
+                .with_context(|| format!("{:?} is not a depth", line))
+        })
+    }
+
+    fn part1(depths: &Self::Input) -> Result<usize> {
+        Ok(depths
+            .iter()
+            .zip(depths.iter().skip(1))
+            .filter(|(current, next)| current < next)
+            .count())
+    }
+
+    fn part2(depths: &Self::Input) -> Result<usize> {
+        let iter = depths.windows(3).map(|window| window.iter().sum::<i32>());
+
+        Ok(iter
+            .clone()
+            .zip(iter.skip(1))
+            .filter(|(current, next)| current < next)
+            .count())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bad_depths_are_reported() {
+        let err = Day1::parse("199\n2OO\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: \"2OO\" is not a depth: invalid digit found in string"
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use anyhow::{Context, Result};
+use aoc_common::{parse::parse_lines, ParseError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Round,
+    Square,
+    Curly,
+    Angle,
+}
+
+impl Kind {
+    /// What a corrupted line scores when this kind closes out of turn.
+    fn corrupted_score(self) -> u32 {
+        match self {
+            Kind::Round => 3,
+            Kind::Square => 57,
+            Kind::Curly => 1197,
+            Kind::Angle => 25137,
+        }
+    }
+
+    /// What closing this kind adds to an incomplete line's score.
+    fn completion_score(self) -> u64 {
+        match self {
+            Kind::Round => 1,
+            Kind::Square => 2,
+            Kind::Curly => 3,
+            Kind::Angle => 4,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Bracket {
+    Open(Kind),
+    Close(Kind),
+}
+
+/// One line of the navigation subsystem.
+#[derive(Debug)]
+pub struct Line {
+    brackets: Vec<Bracket>,
+}
+
+fn parse_line(line: &str) -> Result<Line> {
+    let brackets = line
+        .char_indices()
+        .map(|(i, c)| {
+            Ok(match c {
+                '(' => Bracket::Open(Kind::Round),
+                '[' => Bracket::Open(Kind::Square),
+                '{' => Bracket::Open(Kind::Curly),
+                '<' => Bracket::Open(Kind::Angle),
+                ')' => Bracket::Close(Kind::Round),
+                ']' => Bracket::Close(Kind::Square),
+                '}' => Bracket::Close(Kind::Curly),
+                '>' => Bracket::Close(Kind::Angle),
+                _ => {
+                    return Err(ParseError::at(
+                        line,
+                        &line[i..],
+                        format!("{:?} is not a bracket", c),
+                    ))
+                }
+            })
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Line { brackets })
+}
+
+/// How far a line gets before it goes wrong.
+enum Outcome {
+    /// A bracket closed a chunk of another kind.
+    Corrupted(Kind),
+    /// The line ended with these chunks still open, innermost last.
+    Incomplete(Vec<Kind>),
+    /// Every chunk was closed, or a bracket closed a chunk that was never opened.
+    Complete,
+}
+
+fn check(line: &Line) -> Outcome {
+    let mut queue = vec![];
+    for bracket in &line.brackets {
+        match *bracket {
+            Bracket::Open(kind) => queue.push(kind),
+            Bracket::Close(kind) => match queue.pop() {
+                Some(open) if open == kind => {}
+                Some(_) => return Outcome::Corrupted(kind),
+                None => return Outcome::Complete,
+            },
+        }
+    }
+
+    if queue.is_empty() {
+        Outcome::Complete
+    } else {
+        Outcome::Incomplete(queue)
+    }
+}
+
+fn part2(lines: &[Line]) -> Result<u64> {
+    let mut part2: Vec<u64> = lines
+        .iter()
+        .filter_map(|line| match check(line) {
+            Outcome::Incomplete(queue) => {
+                Some(queue.into_iter().rev().fold(0, |score, unmatched| {
+                    score * 5 + unmatched.completion_score()
+                }))
+            }
+            Outcome::Corrupted(_) | Outcome::Complete => None,
+        })
+        .collect::<Vec<_>>();
+
+    part2.sort_unstable();
+    part2
+        .get(part2.len() / 2)
+        .copied()
+        .context("there are no incomplete lines")
+}
+
+fn part1(lines: &[Line]) -> u32 {
+    lines
+        .iter()
+        .map(|line| match check(line) {
+            Outcome::Corrupted(kind) => kind.corrupted_score(),
+            Outcome::Incomplete(_) | Outcome::Complete => 0,
+        })
+        .sum::<u32>()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Line>;
+    type Part1 = u32;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_lines(input, parse_line)
+    }
+
+    fn part1(lines: &Self::Input) -> Result<u32> {
+        Ok(part1(lines))
+    }
+
+    fn part2(lines: &Self::Input) -> Result<u64> {
+        part2(lines)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn part2_works() {
+        let input = r#"[({(<(())[]>[[{[]{<()<>>
+[(()[<>])]({[<{<<[]>>(
+{([(<{}[<>[]}>{[]{[(<()>
+(((({<>}<{<{<>}{[]{[]{}
+[[<[([]))<([[{}[[()]]]
+[{[{({}]{}}([{[{{{}}([]
+{<[[]]>}<{[{[{[]{()[[[]
+[<(<(<(<{}))><([]([]()
+<{([([[(<>()){}]>(<<{{
+<{([{{}}[<[[[<>{}]]]>[]]"#;
+        let lines = Day10::parse(input).unwrap();
+        assert_eq!(part1(&lines), 26397);
+        assert_eq!(part2(&lines).unwrap(), 288957)
+    }
+
+    #[test]
+    fn non_brackets_are_rejected() {
+        let err = Day10::parse("[({(<(())[]>[[{[]{<()<>>\n[(()[<>])]x({[<{<<[]>>(").unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 11: 'x' is not a bracket");
+    }
+}
@@ -0,0 +1,121 @@
+use anyhow::Result;
+use aoc_common::{Grid, Solution};
+type Map = Grid<u8>;
+
+fn step(map: &mut Map) -> Result<usize> {
+    map.values_mut().for_each(|item| {
+        *item += 1;
+    });
+
+    let mut flashers: std::collections::HashSet<(usize, usize)> = std::collections::HashSet::new();
+
+    // find all not in flashers, the coordinates that are > 9
+    // add it to a list, flashers
+    // add 1 to all cells
+    // repeat 1 until none can be found
+    while map
+        .iter()
+        .any(|(coord, c)| c > &9 && !flashers.contains(&coord))
+    {
+        for coord in map.coords() {
+            if map[coord] > 9 && flashers.insert(coord) {
+                for neighbour in map.neighbours8(coord) {
+                    map[neighbour] += 1;
+                }
+            }
+        }
+    }
+    let count = flashers.len();
+    for coord in flashers {
+        map[coord] = 0;
+    }
+
+    Ok(count)
+}
+
+fn parse_input(input: &str) -> Result<Map> {
+    Grid::parse_digits(input)
+}
+
+fn part1(map: &Map) -> Result<usize> {
+    let mut total = 0;
+    let mut map = map.clone();
+
+    for _ in 0..100 {
+        total += step(&mut map)?;
+    }
+    Ok(total)
+}
+
+fn part2(map: &Map) -> Result<usize> {
+    let mut count = 0;
+    let mut map = map.clone();
+
+    loop {
+        if map.values().all(|c| c == &0) {
+            break
+        }
+        step(&mut map)?;
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Map;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(map: &Self::Input) -> Result<usize> {
+        part1(map)
+    }
+
+    fn part2(map: &Self::Input) -> Result<usize> {
+        part2(map)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn part1_works() -> Result<()> {
+        let input = r#"5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526"#;
+        let mut map = parse_input(input)?;
+        assert_eq!(step(&mut map)?, 0);
+        assert_eq!(step(&mut map)?, 35);
+        Ok(())
+    }
+
+    #[test]
+    fn part2_works() -> Result<()> {
+        let input = r#"5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526"#;
+        assert_eq!(part2(&parse_input(input)?)?, 195);
+        Ok(())
+    }
+}
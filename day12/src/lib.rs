@@ -0,0 +1,249 @@
+use anyhow::{Context, Error, Result};
+use aoc_common::{parse::parse_lines, ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+
+#[derive(Debug)]
+struct Graph<'a> {
+    inner: HashMap<&'a str, HashSet<&'a str>>,
+}
+
+#[derive(Clone, PartialOrd, Ord, Eq, PartialEq)]
+struct Path<'a> {
+    inner: Vec<&'a str>,
+}
+
+impl<'a> fmt::Debug for Path<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.inner.join(","))
+    }
+}
+
+impl<'a> Path<'a> {
+    fn unvisitable_nodes(&self) -> HashSet<&str> {
+        self.inner
+            .iter()
+            .cloned()
+            .filter(|item| item.chars().all(|c| c.is_lowercase()))
+            .collect()
+    }
+}
+
+impl<'a> DerefMut for Path<'a> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<'a> Deref for Path<'a> {
+    type Target = Vec<&'a str>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<'a> Graph<'a> {
+    fn trace_nodes(
+        &'a self,
+        path: Path<'a>,
+        current_node: &'a str,
+        double_visit: bool,
+    ) -> Option<Vec<Path<'a>>> {
+        if current_node == "end" {
+            return Some(vec![path]);
+        };
+
+        let next_nodes = self.inner.get(current_node)?;
+
+        let visitable_nodes = {
+            let unvisitable = path.unvisitable_nodes();
+
+            next_nodes
+                .iter()
+                .filter(|&n| !unvisitable.contains(n))
+                .collect::<Vec<_>>()
+        };
+
+        let mut single_visits = visitable_nodes
+            .into_iter()
+            .flat_map(|&node| {
+                let mut new_path = path.clone();
+                new_path.push(node);
+                self.trace_nodes(new_path, node, double_visit)
+            })
+            .flatten()
+            .collect::<Vec<_>>();
+
+        let double_visit = if double_visit {
+            next_nodes
+                .iter()
+                .filter(|node| !["start", "end"].contains(node))
+                .flat_map(|&node| {
+                    let mut new_path = path.clone();
+                    new_path.push(node);
+                    self.trace_nodes(new_path, node, false)
+                })
+                .flatten()
+                .collect::<Vec<_>>()
+        } else {
+            vec![]
+        };
+
+        if single_visits.is_empty() && double_visit.is_empty() {
+            return None;
+        }
+
+        single_visits.extend(double_visit);
+        single_visits.sort();
+        single_visits.dedup();
+
+        Some(single_visits)
+    }
+
+    fn part1(&self) -> Result<usize> {
+        let path = Path {
+            inner: vec!["start"],
+        };
+        let nodes = self
+            .trace_nodes(path, "start", false)
+            .context("there is no path from start to end")?;
+        Ok(nodes.len())
+    }
+
+    fn part2(&self) -> Result<usize> {
+        let path = Path {
+            inner: vec!["start"],
+        };
+        let mut nodes = self
+            .trace_nodes(path, "start", true)
+            .context("there is no path from start to end")?;
+        nodes.sort();
+        Ok(nodes.len())
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Graph<'a> {
+    type Error = Error;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        let mut inner = HashMap::new();
+
+        let edges = parse_lines(s, |line| {
+            let (left, right) = line
+                .split_once('-')
+                .ok_or_else(|| ParseError::at(line, line, "expected `<cave>-<cave>`"))?;
+
+            for cave in [left, right] {
+                if cave.is_empty() || !cave.chars().all(|c| c.is_ascii_alphabetic()) {
+                    return Err(
+                        ParseError::at(line, cave, format!("{:?} is not a cave", cave)).into(),
+                    );
+                }
+            }
+            if is_big(left) && is_big(right) {
+                return Err(ParseError::at(
+                    line,
+                    line,
+                    "joining two big caves would allow endless paths",
+                )
+                .into());
+            }
+            Ok((left, right))
+        })?;
+
+        for (left, right) in edges {
+            inner.entry(left).or_insert_with(HashSet::new).insert(right);
+            inner.entry(right).or_insert_with(HashSet::new).insert(left);
+        }
+
+        Ok(Self { inner })
+    }
+}
+
+fn is_big(cave: &str) -> bool {
+    cave.chars().all(|c| c.is_uppercase())
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Graph::try_from(input)?;
+        Ok(input.to_owned())
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        Graph::try_from(input.as_str())?.part1()
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        Graph::try_from(input.as_str())?.part2()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn part1_works() {
+        let input = "start-end";
+        let graph = Graph::try_from(input).unwrap();
+        assert_eq!(1, graph.part1().unwrap());
+
+        let input = "start-end
+A-start";
+        let graph = Graph::try_from(input).unwrap();
+        assert_eq!(1, graph.part1().unwrap());
+
+        let input = "start-a
+a-b
+b-end
+a-c
+a-end";
+        let graph = Graph::try_from(input).unwrap();
+        assert_eq!(2, graph.part1().unwrap());
+
+        let input = "start-A
+A-b
+A-end";
+        // start A b A end
+        // start A end
+        let graph = Graph::try_from(input).unwrap();
+        assert_eq!(2, graph.part1().unwrap());
+    }
+
+    #[test]
+    fn part2_works() {
+        let input = r#"start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end"#;
+        let graph = Graph::try_from(input).unwrap();
+        assert_eq!(36, graph.part2().unwrap());
+    }
+
+    #[test]
+    fn bad_edges_are_rejected() {
+        let err = Graph::try_from("start-A\nA-b2").unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 3: \"b2\" is not a cave");
+
+        let err = Graph::try_from("start-A\nA-B").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: joining two big caves would allow endless paths"
+        );
+
+        let graph = Graph::try_from("start-a\nb-end").unwrap();
+        assert!(graph.part1().is_err());
+    }
+}
@@ -0,0 +1,261 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    fmt::Display,
+    str::FromStr,
+};
+
+use anyhow::{bail, Context, Error, Result};
+use aoc_common::{
+    parse::{parse_lines, within},
+    ParseError, Solution,
+};
+
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+struct Coord(usize, usize);
+
+impl FromStr for Coord {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = s
+            .split_once(',')
+            .ok_or_else(|| ParseError::at(s, s, "expected `x,y`"))?;
+        let parse = |n: &str| {
+            n.parse()
+                .map_err(|_| ParseError::at(s, n, format!("{:?} is not a coordinate", n)))
+        };
+        Ok(Self(parse(x)?, parse(y)?))
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Direction {
+    X, // left
+    Y, // up
+}
+
+impl FromStr for Direction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "x" => Self::X,
+            "y" => Self::Y,
+            _ => return Err(ParseError::at(s, s, format!("unknown direction {:?}", s)).into()),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Instruction {
+    direction: Direction,
+    coordinate: usize,
+}
+
+impl Instruction {
+    /// Folds the dots, failing if one is more than twice as far out as the
+    /// fold and would end up before the start of the paper.
+    fn apply(&self, coords: &mut [Coord]) -> Result<()> {
+        let (axis, fold) = match self.direction {
+            Direction::X => ("x", self.coordinate),
+            Direction::Y => ("y", self.coordinate),
+        };
+
+        for Coord(x, y) in coords.iter_mut() {
+            let n = match self.direction {
+                Direction::X => x,
+                Direction::Y => y,
+            };
+            if *n <= fold {
+                continue;
+            }
+            match fold.checked_sub(*n - fold) {
+                Some(folded) => *n = folded,
+                None => bail!(
+                    "folding along {}={} would move the dot at {}={} off the paper",
+                    axis,
+                    fold,
+                    axis,
+                    n
+                ),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fold = s
+            .strip_prefix("fold along ")
+            .ok_or_else(|| ParseError::at(s, s, "expected `fold along <axis>=<n>`"))?;
+        let (direction, coord) = fold
+            .split_once('=')
+            .ok_or_else(|| ParseError::at(s, fold, "expected `<axis>=<n>`"))?;
+        Ok(Self {
+            direction: within(s, direction, str::parse)?,
+            coordinate: coord.parse().map_err(|_| {
+                ParseError::at(s, coord, format!("{:?} is not a coordinate", coord))
+            })?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Manual {
+    dots: Vec<Coord>,
+    /// Each fold with the line it came from.
+    instructions: VecDeque<(usize, Instruction)>,
+}
+
+impl Display for Manual {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let max_x = self.dots.iter().max_by_key(|Coord(x, _)| x).unwrap().0;
+        let max_y = self.dots.iter().max_by_key(|Coord(_, y)| y).unwrap().1;
+        let set = self.dots.iter().cloned().collect::<HashSet<Coord>>();
+
+        f.write_str(
+            &(0..=max_y)
+                .map(|y| {
+                    (0..=max_x)
+                        .map(|x| {
+                            if set.contains(&Coord(x, y)) {
+                                '█'
+                            } else {
+                                ' '
+                            }
+                        })
+                        .collect::<String>()
+                })
+                .collect::<Vec<String>>()
+                .join("\n"),
+        )
+    }
+}
+
+impl Manual {
+    /// Makes the next fold, if there are any left.
+    fn step(&mut self) -> Result<bool> {
+        let Some((line, fold)) = self.instructions.pop_front() else {
+            return Ok(false);
+        };
+
+        fold.apply(&mut self.dots)
+            .map_err(|e| ParseError::new(line, 1, e))?;
+
+        Ok(true)
+    }
+
+    /// The dots as rows of lit cells, from the origin to the furthest dot.
+    fn picture(&self) -> Vec<Vec<bool>> {
+        let max_x = self.dots.iter().map(|Coord(x, _)| *x).max().unwrap_or(0);
+        let max_y = self.dots.iter().map(|Coord(_, y)| *y).max().unwrap_or(0);
+        let set = self.dots.iter().cloned().collect::<HashSet<Coord>>();
+
+        (0..=max_y)
+            .map(|y| (0..=max_x).map(|x| set.contains(&Coord(x, y))).collect())
+            .collect()
+    }
+
+    fn count(&self) -> usize {
+        self.dots.iter().cloned().collect::<HashSet<Coord>>().len()
+    }
+}
+
+fn parse_instructions(input: &str) -> Result<Manual> {
+    let (dots, instructions) = input
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::at(input, input, "expected dots, a blank line, then folds"))?;
+
+    let dots = within(input, dots, |dots| parse_lines(dots, str::parse))?;
+    if dots.is_empty() {
+        return Err(ParseError::at(input, input, "expected at least one dot").into());
+    }
+
+    let folds: Vec<Instruction> = within(input, instructions, |instructions| {
+        parse_lines(instructions, str::parse)
+    })?;
+    let lines = instructions
+        .lines()
+        .map(|line| ParseError::at(input, line, "").line);
+
+    Ok(Manual {
+        dots,
+        instructions: lines.zip(folds).collect(),
+    })
+}
+
+fn part1(manual: &Manual) -> Result<usize> {
+    let mut manual = manual.clone();
+    manual.step()?;
+    Ok(manual.count())
+}
+
+fn part2(manual: &Manual) -> Result<String> {
+    let mut manual = manual.clone();
+    while manual.step()? {}
+
+    aoc_common::ocr::read(&manual.picture())
+        .with_context(|| format!("couldn't read the code from\n{}", manual))
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Manual;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_instructions(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<String> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bad_manuals_are_reported() {
+        let err = Day13::parse("6,10\n0,1x\n\nfold along y=7").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: \"1x\" is not a coordinate"
+        );
+
+        let err = Day13::parse("6,10\n\nfold along y=7\nfold along z=5").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 12: unknown direction \"z\""
+        );
+
+        let err = Day13::parse("6,10\n\nfold y=7").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 1: expected `fold along <axis>=<n>`"
+        );
+
+        let manual = Day13::parse("10,0\n1,1\n\nfold along x=2").unwrap();
+        assert_eq!(
+            Day13::part1(&manual).unwrap_err().to_string(),
+            "line 4, column 1: folding along x=2 would move the dot at x=10 off the paper"
+        );
+
+        let manual = Day13::parse("0,9\n\nfold along y=6\nfold along y=1").unwrap();
+        assert_eq!(Day13::part1(&manual).unwrap(), 1);
+        assert_eq!(
+            Day13::part2(&manual).unwrap_err().to_string(),
+            "line 4, column 1: folding along y=1 would move the dot at y=3 off the paper"
+        );
+    }
+}
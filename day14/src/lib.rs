@@ -0,0 +1,194 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use aoc_common::{
+    parse::{parse_lines, within},
+    ParseError, Solution,
+};
+
+type Rules = HashMap<(char, char), char>;
+
+fn parse_rule(line: &str) -> Result<((char, char), char)> {
+    let (tuple, insert) = line
+        .split_once(" -> ")
+        .ok_or_else(|| ParseError::at(line, line, "expected `<pair> -> <element>`"))?;
+
+    let tuple_chars = tuple.chars().collect::<Vec<_>>();
+    let insert_chars = insert.chars().collect::<Vec<_>>();
+
+    match (&tuple_chars[..], &insert_chars[..]) {
+        (&[left, right], &[insert]) => Ok(((left, right), insert)),
+        ([_, _], _) => Err(ParseError::at(line, insert, "expected a single element").into()),
+        _ => Err(ParseError::at(line, tuple, "expected a pair of elements").into()),
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Vec<char>, Rules)> {
+    let (polymer, rules) = input.split_once("\n\n").ok_or_else(|| {
+        ParseError::at(
+            input,
+            input,
+            "expected a template, a blank line, then rules",
+        )
+    })?;
+    let rules = within(input, rules, |rules| parse_lines(rules, parse_rule))?
+        .into_iter()
+        .collect();
+
+    let polymer = polymer.chars().collect::<Vec<_>>();
+    if polymer.len() < 2 {
+        return Err(ParseError::new(1, 1, "the template needs at least two elements").into());
+    }
+
+    Ok((polymer, rules))
+}
+
+fn find_result((polymer, rules): &(Vec<char>, Rules), iterations: usize) -> Result<usize> {
+    let mut pairs: HashMap<(char, char), usize> = HashMap::new();
+
+    for chars in polymer.windows(2) {
+        let mut key = chars.iter().cloned();
+        let key = (key.next().unwrap(), key.next().unwrap());
+        *pairs.entry(key).or_insert(0) += 1;
+    }
+
+    for _ in 0..iterations {
+        let mut next_gen = HashMap::new();
+
+        for (k, count) in pairs.into_iter() {
+            if let Some(new) = rules.get(&k) {
+                for pair in [(k.0, *new), (*new, k.1)].into_iter() {
+                    *next_gen.entry(pair).or_insert(0) += count;
+                }
+            } else {
+                *next_gen.entry(k).or_insert(0) += count;
+            }
+        }
+
+        pairs = next_gen;
+    }
+
+    let mut left_counts: HashMap<char, usize> = HashMap::new();
+    for ((left, _), count) in &pairs {
+        *left_counts.entry(*left).or_insert(0) += count;
+    }
+
+    let mut right_counts: HashMap<char, usize> = HashMap::new();
+    for ((_, right), count) in &pairs {
+        *right_counts.entry(*right).or_insert(0) += count;
+    }
+
+    for (k, mut v) in right_counts.into_iter() {
+        if let Some(left_val) = left_counts.get_mut(&k) {
+            if *left_val != v {
+                *left_val = *left_val.max(&mut v);
+            }
+        }
+    }
+
+    let mut counts_as_vec = left_counts.into_iter().collect::<Vec<_>>();
+    counts_as_vec.sort_by_key(|(_, count)| *count);
+    let result = counts_as_vec[counts_as_vec.len() - 1].1 - counts_as_vec[0].1;
+
+    Ok(result)
+}
+
+fn part1(input: &(Vec<char>, Rules)) -> Result<usize> {
+    find_result(input, 10)
+}
+
+fn part2(input: &(Vec<char>, Rules)) -> Result<usize> {
+    find_result(input, 40)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = (Vec<char>, Rules);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn part2_works() {
+        let input = "NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C";
+        assert_eq!(part2(&parse_input(input).unwrap()).unwrap(), 2188189693529)
+    }
+
+    #[test]
+    fn part1_works() {
+        let input = "NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C";
+        assert_eq!(part1(&parse_input(input).unwrap()).unwrap(), 1588)
+    }
+
+    #[test]
+    fn bad_rules_are_reported() {
+        let err = parse_input("NNCB\n\nCH -> B\nHHH -> N").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 1: expected a pair of elements"
+        );
+
+        let err = parse_input("NNCB\n\nCH -> B\nHH -> ").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 7: expected a single element"
+        );
+
+        let err = parse_input("N\n\nCH -> B").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: the template needs at least two elements"
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+use anyhow::Result;
+use aoc_common::{Grid, ParseError, Solution};
+
+fn parse_input(input: &str) -> Result<Grid<u8>> {
+    let map = Grid::parse_digits(input)?;
+    if map.width() == 0 {
+        return Err(ParseError::new(1, 1, "the map is empty").into());
+    }
+    Ok(map)
+}
+
+fn find_path(map: &Grid<u8>) -> Result<u32> {
+    let start_cost = map[(0, 0)] as u32;
+    let mut open_set = BinaryHeap::new();
+    open_set.push(Reverse((start_cost, (0, 0))));
+    let mut gscore_map = HashMap::new();
+    let goal = (map.width() - 1, map.height() - 1);
+
+    let mut came_from = HashMap::new();
+
+    while let Some(Reverse((parent_cost, (px, py)))) = open_set.pop() {
+        for (x, y) in map.neighbours4((px, py)) {
+            let actual_cost = map[(x, y)] as u32 + parent_cost;
+
+            match gscore_map.entry((x, y)) {
+                std::collections::hash_map::Entry::Occupied(mut val)
+                    if val.get() > &actual_cost =>
+                {
+                    *val.get_mut() = actual_cost;
+                }
+                std::collections::hash_map::Entry::Vacant(e) => {
+                    e.insert(actual_cost);
+                }
+                _ => continue,
+            }
+
+            came_from.insert((x, y), (px, py));
+            open_set.push(Reverse((actual_cost, (x, y))))
+        }
+
+        if (px, py) == goal {
+            break;
+        } // TODO: add the final value?
+    }
+
+    // the start is never entered from a neighbour, so a 1x1 map has no score for it
+    Ok(gscore_map.get(&goal).map_or(0, |cost| cost - start_cost))
+}
+
+fn part1(map: &Grid<u8>) -> Result<u32> {
+    find_path(map)
+}
+
+fn tile_map(map: Grid<u8>) -> Result<Grid<u8>> {
+    let (width, height) = (map.width(), map.height());
+    let mut cells = Vec::with_capacity(width * height * 25);
+
+    for y in 0..height * 5 {
+        for x in 0..width * 5 {
+            let mut num = map[(x % width, y % height)] + (x / width + y / height) as u8;
+
+            if num > 9 {
+                num %= 9;
+            }
+            cells.push(num);
+        }
+    }
+
+    Grid::new(width * 5, height * 5, cells)
+}
+
+fn part2(map: &Grid<u8>) -> Result<u32> {
+    let tiled_map = tile_map(map.clone())?;
+    find_path(&tiled_map)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Grid<u8>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(map: &Self::Input) -> Result<u32> {
+        part1(map)
+    }
+
+    fn part2(map: &Self::Input) -> Result<u32> {
+        part2(map)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn part1_works() {
+        let input = r#"1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581"#;
+        assert_eq!(part1(&parse_input(input).unwrap()).unwrap(), 40);
+    }
+
+    #[test]
+    fn part2_works() {
+        let input = r#"1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581"#;
+        assert_eq!(part2(&parse_input(input).unwrap()).unwrap(), 315);
+    }
+
+    #[test]
+    fn tiling_works() {
+        let input = Grid::from_rows(vec![vec![9]]).unwrap();
+        assert_eq!(
+            tile_map(input).unwrap(),
+            Grid::from_rows(vec![
+                vec![9, 1, 2, 3, 4],
+                vec![1, 2, 3, 4, 5],
+                vec![2, 3, 4, 5, 6],
+                vec![3, 4, 5, 6, 7],
+                vec![4, 5, 6, 7, 8],
+            ])
+            .unwrap()
+        )
+    }
+
+    #[test]
+    fn degenerate_maps() {
+        assert_eq!(part1(&parse_input("7").unwrap()).unwrap(), 0);
+        assert_eq!(
+            parse_input("").unwrap_err().to_string(),
+            "line 1, column 1: the map is empty"
+        );
+    }
+}
@@ -0,0 +1,16 @@
+//! Decodes arbitrary input in both modes, which must fail cleanly rather than
+//! panic. Run with `cargo fuzz run decode` from `day16`.
+
+#![no_main]
+
+use day16::{decode, disassemble, Mode};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    for mode in [Mode::Strict, Mode::Lenient] {
+        if let Ok(packet) = decode(input, mode) {
+            let _ = packet.apply();
+        }
+    }
+    let _ = disassemble(input);
+});
@@ -0,0 +1,167 @@
+use std::fmt::Write;
+
+use anyhow::Result;
+
+use crate::{decode, BitReader, Mode, Packet, PacketType, LITERAL_TYPE_ID};
+
+/// Writes `packet`, which `reader` is at the start of, reading the layout
+/// the decoded packet doesn't keep back out of the bits.
+fn write_packet(
+    out: &mut String,
+    reader: &mut BitReader,
+    packet: &Packet,
+    depth: usize,
+) -> Result<()> {
+    let indent = "  ".repeat(depth);
+    let at = reader.position();
+    reader.read(6, "version and type id")?;
+
+    match &packet.kind {
+        PacketType::Literal(value) => {
+            let mut groups = String::new();
+            loop {
+                let pos = reader.position();
+                let group = reader.read(5, "literal group")?;
+                write!(groups, " [{}]{:04b}", pos, group & 0xf)?;
+                if group >> 4 == 0 {
+                    break;
+                }
+            }
+
+            writeln!(
+                out,
+                "{}[{}] v{} type {} literal, groups{} = {}",
+                indent, at, packet.version, LITERAL_TYPE_ID, groups, value
+            )?;
+        }
+        PacketType::Operator(opkind, packets) => {
+            let (length_type, length, unit) = match reader.read(1, "length type id")? {
+                0 => (0, reader.read(15, "sub-packet length")?, "bits"),
+                _ => (1, reader.read(11, "sub-packet count")?, "sub-packets"),
+            };
+            let end = reader.position() + length;
+            // a malformed packet is still laid out, with why it won't evaluate
+            let value = match packet.apply() {
+                Ok(value) => value.to_string(),
+                Err(e) => format!("error: {}", e),
+            };
+
+            writeln!(
+                out,
+                "{}[{}] v{} type {} {:?}, length type {}: {} {} = {}",
+                indent,
+                at,
+                packet.version,
+                opkind.type_id(),
+                opkind,
+                length_type,
+                length,
+                unit,
+                value
+            )?;
+
+            for packet in packets {
+                write_packet(out, reader, packet, depth + 1)?;
+            }
+            if length_type == 0 && reader.position() < end {
+                let left_over = end - reader.position();
+                writeln!(
+                    out,
+                    "{}  [{}] {} bits left over",
+                    indent,
+                    reader.position(),
+                    left_over
+                )?;
+                reader.take(left_over, "left over bits")?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Decodes a transmission in [`Mode::Lenient`] and lays it out as an
+/// indented tree, one packet per line: the bit it starts at, its version and
+/// type id, how its sub-packets are measured or where each literal group
+/// starts, and what it evaluates to.
+pub fn disassemble(input: &str) -> Result<String> {
+    let packet = decode(input, Mode::Lenient)?;
+    let mut reader = BitReader::new(input)?;
+    let mut out = String::new();
+
+    write_packet(&mut out, &mut reader, &packet, 0)?;
+    if reader.remaining() > 0 {
+        writeln!(
+            out,
+            "[{}] {} bits of padding",
+            reader.position(),
+            reader.remaining()
+        )?;
+    }
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bit_lengths_are_shown() {
+        assert_eq!(
+            disassemble("38006F45291200").unwrap(),
+            "[0] v1 type 6 LessThan, length type 0: 27 bits = 1
+  [22] v6 type 4 literal, groups [28]1010 = 10
+  [33] v2 type 4 literal, groups [39]0001 [44]0100 = 20
+[49] 7 bits of padding
+"
+        );
+    }
+
+    #[test]
+    fn nesting_is_shown() {
+        assert_eq!(
+            disassemble("9C0141080250320F1802104A08").unwrap(),
+            "[0] v4 type 7 EqualTo, length type 0: 80 bits = 1
+  [22] v2 type 0 Sum, length type 1: 2 sub-packets = 4
+    [40] v2 type 4 literal, groups [46]0001 = 1
+    [51] v4 type 4 literal, groups [57]0011 = 3
+  [62] v6 type 1 Product, length type 1: 2 sub-packets = 4
+    [80] v0 type 4 literal, groups [86]0010 = 2
+    [91] v2 type 4 literal, groups [97]0010 = 2
+[102] 2 bits of padding
+"
+        );
+    }
+
+    #[test]
+    fn long_literals_show_every_group() {
+        assert_eq!(
+            disassemble("D2FE28").unwrap(),
+            "[0] v6 type 4 literal, groups [6]0111 [11]1110 [16]0101 = 2021\n[21] 3 bits of padding\n"
+        );
+    }
+
+    #[test]
+    fn malformed_transmissions_are_shown() {
+        assert_eq!(
+            disassemble("D2FE29").unwrap(),
+            "[0] v6 type 4 literal, groups [6]0111 [11]1110 [16]0101 = 2021\n[21] 3 bits of padding\n"
+        );
+        assert_eq!(
+            disassemble("38007B45291200").unwrap(),
+            "[0] v1 type 6 LessThan, length type 0: 30 bits = 1
+  [22] v6 type 4 literal, groups [28]1010 = 10
+  [33] v2 type 4 literal, groups [39]0001 [44]0100 = 20
+  [49] 3 bits left over
+[52] 4 bits of padding
+"
+        );
+        assert_eq!(
+            disassemble("02000").unwrap(),
+            "[0] v0 type 0 Sum, length type 1: 0 sub-packets = error: Sum can't apply to 0 sub-packets
+[18] 2 bits of padding
+"
+        );
+    }
+}
@@ -0,0 +1,307 @@
+use anyhow::{anyhow, bail, Result};
+
+use crate::{BigUint, Packet, PacketType, LITERAL_TYPE_ID};
+
+/// The most sub-packets an operator can count in its 11 bit length field.
+const MAX_COUNT: usize = (1 << 11) - 1;
+/// The most bits of sub-packets an operator can measure in its 15 bit length field.
+const MAX_BITS: usize = (1 << 15) - 1;
+
+/// How an operator packet says where its sub-packets end: length type id 0
+/// gives their total length in bits, 1 gives how many there are.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthType {
+    Bits,
+    Count,
+}
+
+/// Bits packed most significant first into bytes.
+#[derive(Debug, Default, Clone)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    len: usize,
+}
+
+impl BitWriter {
+    fn push_bit(&mut self, bit: bool) {
+        if self.len.is_multiple_of(8) {
+            self.bytes.push(0);
+        }
+        if bit {
+            self.bytes[self.len / 8] |= 0x80 >> (self.len % 8);
+        }
+        self.len += 1;
+    }
+
+    /// Appends the low `width` bits of `value`.
+    fn push(&mut self, value: usize, width: usize) {
+        for i in (0..width).rev() {
+            self.push_bit(value >> i & 1 == 1);
+        }
+    }
+
+    fn bit(&self, i: usize) -> bool {
+        self.bytes[i / 8] & 0x80 >> (i % 8) != 0
+    }
+
+    fn append(&mut self, other: &BitWriter) {
+        for i in 0..other.len {
+            self.push_bit(other.bit(i));
+        }
+    }
+
+    /// The bits as hex digits, zero padded to a whole digit.
+    fn to_hex(&self) -> String {
+        self.bytes
+            .iter()
+            .flat_map(|byte| [byte >> 4, byte & 0xf])
+            .take(self.len.div_ceil(4))
+            .map(|digit| {
+                char::from_digit(digit as u32, 16)
+                    .unwrap()
+                    .to_ascii_uppercase()
+            })
+            .collect()
+    }
+}
+
+fn write_literal(w: &mut BitWriter, value: &BigUint) {
+    let digits = value.to_radix_be(16);
+
+    for (i, digit) in digits.iter().enumerate() {
+        w.push_bit(i + 1 < digits.len());
+        w.push(*digit as usize, 4);
+    }
+}
+
+fn write_packet(w: &mut BitWriter, packet: &Packet, length_type: Option<LengthType>) -> Result<()> {
+    if packet.version > 7 {
+        bail!("version {} doesn't fit in 3 bits", packet.version);
+    }
+    w.push(packet.version, 3);
+
+    let (opkind, packets) = match &packet.kind {
+        PacketType::Literal(value) => {
+            w.push(LITERAL_TYPE_ID, 3);
+            write_literal(w, value);
+            return Ok(());
+        }
+        PacketType::Operator(opkind, packets) => (opkind, packets),
+    };
+
+    opkind.check_arity(packets.len()).map_err(|e| anyhow!(e))?;
+    w.push(opkind.type_id(), 3);
+
+    let mut body = BitWriter::default();
+    for packet in packets {
+        write_packet(&mut body, packet, length_type)?;
+    }
+
+    match length_type {
+        Some(LengthType::Count) | None if packets.len() <= MAX_COUNT => {
+            w.push(1, 1);
+            w.push(packets.len(), 11);
+        }
+        Some(LengthType::Bits) | None if body.len <= MAX_BITS => {
+            w.push(0, 1);
+            w.push(body.len, 15);
+        }
+        Some(LengthType::Count) => {
+            bail!("{} sub-packets don't fit in an 11 bit count", packets.len())
+        }
+        Some(LengthType::Bits) | None => bail!(
+            "{} bits of sub-packets don't fit in a 15 bit length",
+            body.len
+        ),
+    }
+    w.append(&body);
+
+    Ok(())
+}
+
+/// Encodes `packet` as a BITS transmission, counting each operator's
+/// sub-packets where there are few enough and giving their length in bits
+/// otherwise.
+pub fn encode(packet: &Packet) -> Result<String> {
+    let mut w = BitWriter::default();
+    write_packet(&mut w, packet, None)?;
+    Ok(w.to_hex())
+}
+
+/// Encodes `packet` as a BITS transmission, giving every operator's
+/// sub-packets with `length_type`.
+pub fn encode_with(packet: &Packet, length_type: LengthType) -> Result<String> {
+    let mut w = BitWriter::default();
+    write_packet(&mut w, packet, Some(length_type))?;
+    Ok(w.to_hex())
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{decode, parse_input, Mode, OpKind};
+
+    fn literal(version: usize, value: u32) -> Packet {
+        Packet {
+            version,
+            kind: PacketType::Literal(value.into()),
+        }
+    }
+
+    fn operator(version: usize, opkind: OpKind, packets: Vec<Packet>) -> Packet {
+        Packet {
+            version,
+            kind: PacketType::Operator(opkind, packets),
+        }
+    }
+
+    #[test]
+    fn literals_encode() {
+        assert_eq!(encode(&literal(6, 2021)).unwrap(), "D2FE28");
+        assert_eq!(encode(&literal(0, 0)).unwrap(), "100");
+    }
+
+    #[test]
+    fn long_literals_round_trip() {
+        let value = BigUint::from(u128::MAX) * BigUint::from(u128::MAX);
+        let packet = Packet {
+            version: 3,
+            kind: PacketType::Literal(value),
+        };
+        assert_eq!(parse_input(&encode(&packet).unwrap()).unwrap(), packet);
+    }
+
+    #[test]
+    fn operators_encode() {
+        let packet = parse_input("EE00D40C823060").unwrap();
+        assert_eq!(
+            encode_with(&packet, LengthType::Count).unwrap(),
+            "EE00D40C82306"
+        );
+
+        let packet = parse_input("38006F45291200").unwrap();
+        assert_eq!(
+            encode_with(&packet, LengthType::Bits).unwrap(),
+            "38006F4529120"
+        );
+    }
+
+    #[test]
+    fn examples_round_trip() {
+        let examples = [
+            "8A004A801A8002F478",
+            "620080001611562C8802118E34",
+            "C0015000016115A2E0802F182340",
+            "A0016C880162017C3686B18A3D4780",
+            "9C0141080250320F1802104A08",
+        ];
+
+        for hex in examples {
+            let packet = parse_input(hex).unwrap();
+            for length_type in [LengthType::Bits, LengthType::Count] {
+                let encoded = encode_with(&packet, length_type).unwrap();
+                assert_eq!(parse_input(&encoded).unwrap(), packet);
+            }
+            assert_eq!(parse_input(&encode(&packet).unwrap()).unwrap(), packet);
+        }
+    }
+
+    #[test]
+    fn empty_operators_only_decode_leniently() {
+        // a sum measured in bits holding a literal 0 and an empty sum, which
+        // is nothing but zero bits
+        let hex = "00008440000000";
+        assert_eq!(
+            parse_input(hex).unwrap_err().to_string(),
+            "line 1, column 9: bit 33: Sum can't apply to 0 sub-packets"
+        );
+        assert_eq!(
+            decode(hex, Mode::Lenient).unwrap(),
+            operator(
+                0,
+                OpKind::Sum,
+                vec![literal(0, 0), operator(0, OpKind::Sum, vec![])],
+            )
+        );
+    }
+
+    #[test]
+    fn unencodable_packets_are_rejected() {
+        assert_eq!(
+            encode(&literal(8, 1)).unwrap_err().to_string(),
+            "version 8 doesn't fit in 3 bits"
+        );
+        assert_eq!(
+            encode(&operator(0, OpKind::Maximum, vec![]))
+                .unwrap_err()
+                .to_string(),
+            "Maximum can't apply to 0 sub-packets"
+        );
+        assert_eq!(
+            encode(&operator(0, OpKind::Sum, vec![]))
+                .unwrap_err()
+                .to_string(),
+            "Sum can't apply to 0 sub-packets"
+        );
+
+        // 11 bits each
+        let many = operator(0, OpKind::Sum, vec![literal(0, 1); 3000]);
+        assert_eq!(
+            encode_with(&many, LengthType::Count)
+                .unwrap_err()
+                .to_string(),
+            "3000 sub-packets don't fit in an 11 bit count"
+        );
+        assert_eq!(
+            encode(&many).unwrap_err().to_string(),
+            "33000 bits of sub-packets don't fit in a 15 bit length"
+        );
+    }
+
+    /// Any packet the encoder accepts: versions up to 7 and operators with
+    /// the right number of sub-packets.
+    fn packets() -> impl Strategy<Value = Packet> {
+        let literal =
+            (0..8usize, prop::collection::vec(any::<u32>(), 0..5)).prop_map(|(version, digits)| {
+                Packet {
+                    version,
+                    kind: PacketType::Literal(BigUint::new(digits)),
+                }
+            });
+
+        literal.prop_recursive(4, 48, 5, |inner| {
+            let some = (
+                prop::sample::select(vec![
+                    OpKind::Sum,
+                    OpKind::Product,
+                    OpKind::Minimum,
+                    OpKind::Maximum,
+                ]),
+                prop::collection::vec(inner.clone(), 1..5),
+            );
+            let two = (
+                prop::sample::select(vec![OpKind::GreaterThan, OpKind::LessThan, OpKind::EqualTo]),
+                prop::collection::vec(inner, 2),
+            );
+            (0..8usize, prop_oneof![some, two])
+                .prop_map(|(version, (opkind, packets))| operator(version, opkind, packets))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn packets_round_trip(packet in packets()) {
+            for encoded in [
+                encode(&packet).unwrap(),
+                encode_with(&packet, LengthType::Bits).unwrap(),
+                encode_with(&packet, LengthType::Count).unwrap(),
+            ] {
+                let decoded = parse_input(&encoded).unwrap();
+                prop_assert_eq!(decoded.apply().unwrap(), packet.apply().unwrap());
+                prop_assert_eq!(&decoded, &packet);
+            }
+        }
+    }
+}
@@ -0,0 +1,304 @@
+//! A small expression syntax that compiles to BITS packets, such as
+//! `max(1, 2*3, 4 < 5)`.
+//!
+//! `+` and `*` build sums and products, `<`, `>` and `==` compare two values,
+//! and `sum(..)`, `product(..)`, `min(..)` and `max(..)` take one or more
+//! operands. Every packet gets version 0.
+
+use anyhow::Result;
+use aoc_common::ParseError;
+
+use crate::{OpKind, Packet, PacketType};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token<'a> {
+    Number(&'a str),
+    Name(&'a str),
+    Open,
+    Close,
+    Comma,
+    Plus,
+    Star,
+    Less,
+    Greater,
+    Equal,
+}
+
+impl Token<'_> {
+    fn describe(&self) -> String {
+        match self {
+            Token::Number(n) => format!("number {}", n),
+            Token::Name(name) => format!("{:?}", name),
+            Token::Open => "'('".to_owned(),
+            Token::Close => "')'".to_owned(),
+            Token::Comma => "','".to_owned(),
+            Token::Plus => "'+'".to_owned(),
+            Token::Star => "'*'".to_owned(),
+            Token::Less => "'<'".to_owned(),
+            Token::Greater => "'>'".to_owned(),
+            Token::Equal => "'=='".to_owned(),
+        }
+    }
+}
+
+/// Splits `src` into tokens, each with the slice of `src` it came from.
+fn tokenize(src: &str) -> Result<Vec<(&str, Token<'_>)>> {
+    let mut tokens = vec![];
+    let mut rest = src.trim_start();
+
+    while let Some(c) = rest.chars().next() {
+        let len = match c {
+            '0'..='9' => rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len()),
+            'a'..='z' | 'A'..='Z' | '_' => rest
+                .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+                .unwrap_or(rest.len()),
+            '=' if rest.starts_with("==") => 2,
+            '(' | ')' | ',' | '+' | '*' | '<' | '>' => 1,
+            _ => {
+                return Err(ParseError::at(src, rest, format!("unexpected {:?}", c)).into());
+            }
+        };
+        let (text, after) = rest.split_at(len);
+        let token = match c {
+            '0'..='9' => Token::Number(text),
+            '(' => Token::Open,
+            ')' => Token::Close,
+            ',' => Token::Comma,
+            '+' => Token::Plus,
+            '*' => Token::Star,
+            '<' => Token::Less,
+            '>' => Token::Greater,
+            '=' => Token::Equal,
+            _ => Token::Name(text),
+        };
+        tokens.push((text, token));
+        rest = after.trim_start();
+    }
+
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    tokens: Vec<(&'a str, Token<'a>)>,
+    next: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<Token<'a>> {
+        self.tokens.get(self.next).map(|(_, token)| *token)
+    }
+
+    /// An error at the next token, or just past the end of the source.
+    fn error(&self, message: impl std::fmt::Display) -> anyhow::Error {
+        let at = match self.tokens.get(self.next) {
+            Some((text, _)) => text,
+            None => &self.src[self.src.trim_end().len()..],
+        };
+        ParseError::at(self.src, at, message).into()
+    }
+
+    fn expect(&mut self, expected: Token, what: &str) -> Result<()> {
+        match self.peek() {
+            Some(token) if token == expected => {
+                self.next += 1;
+                Ok(())
+            }
+            Some(token) => {
+                Err(self.error(format!("expected {}, found {}", what, token.describe())))
+            }
+            None => Err(self.error(format!("expected {}, found the end", what))),
+        }
+    }
+
+    /// `sum`, optionally compared with another `sum`.
+    fn comparison(&mut self) -> Result<Packet> {
+        let left = self.sum()?;
+        let opkind = match self.peek() {
+            Some(Token::Less) => OpKind::LessThan,
+            Some(Token::Greater) => OpKind::GreaterThan,
+            Some(Token::Equal) => OpKind::EqualTo,
+            _ => return Ok(left),
+        };
+        self.next += 1;
+        let right = self.sum()?;
+
+        if let Some(Token::Less | Token::Greater | Token::Equal) = self.peek() {
+            return Err(self.error("comparisons can't be chained, add parentheses"));
+        }
+        Ok(operator(opkind, vec![left, right]))
+    }
+
+    /// One or more `product`s separated by `+`.
+    fn sum(&mut self) -> Result<Packet> {
+        self.chain(Token::Plus, OpKind::Sum, Self::product)
+    }
+
+    /// One or more `operand`s separated by `*`.
+    fn product(&mut self) -> Result<Packet> {
+        self.chain(Token::Star, OpKind::Product, Self::operand)
+    }
+
+    fn chain(
+        &mut self,
+        separator: Token,
+        opkind: OpKind,
+        mut next: impl FnMut(&mut Self) -> Result<Packet>,
+    ) -> Result<Packet> {
+        let mut packets = vec![next(self)?];
+        while self.peek() == Some(separator) {
+            self.next += 1;
+            packets.push(next(self)?);
+        }
+
+        Ok(match packets.len() {
+            1 => packets.remove(0),
+            _ => operator(opkind, packets),
+        })
+    }
+
+    /// A number, a call, or a parenthesised expression.
+    fn operand(&mut self) -> Result<Packet> {
+        match self.peek() {
+            Some(Token::Number(n)) => {
+                let value = n.parse().map_err(|e| self.error(e))?;
+                self.next += 1;
+                Ok(Packet {
+                    version: 0,
+                    kind: PacketType::Literal(value),
+                })
+            }
+            Some(Token::Name(name)) => {
+                let opkind = match name {
+                    "sum" => OpKind::Sum,
+                    "product" => OpKind::Product,
+                    "min" => OpKind::Minimum,
+                    "max" => OpKind::Maximum,
+                    _ => {
+                        return Err(self.error(format!(
+                            "unknown function {:?}, expected sum, product, min or max",
+                            name
+                        )))
+                    }
+                };
+                self.next += 1;
+                self.expect(Token::Open, "'('")?;
+
+                let mut packets = vec![];
+                if self.peek() != Some(Token::Close) {
+                    packets.push(self.comparison()?);
+                    while self.peek() == Some(Token::Comma) {
+                        self.next += 1;
+                        packets.push(self.comparison()?);
+                    }
+                }
+                if packets.len() < opkind.arity().0 {
+                    return Err(self.error(format!("{} needs at least one operand", name)));
+                }
+                self.expect(Token::Close, "',' or ')'")?;
+
+                Ok(operator(opkind, packets))
+            }
+            Some(Token::Open) => {
+                self.next += 1;
+                let packet = self.comparison()?;
+                self.expect(Token::Close, "')'")?;
+                Ok(packet)
+            }
+            Some(token) => Err(self.error(format!("expected a value, found {}", token.describe()))),
+            None => Err(self.error("expected a value, found the end")),
+        }
+    }
+}
+
+fn operator(opkind: OpKind, packets: Vec<Packet>) -> Packet {
+    Packet {
+        version: 0,
+        kind: PacketType::Operator(opkind, packets),
+    }
+}
+
+/// Compiles an expression into the packet that evaluates it.
+pub fn compile(src: &str) -> Result<Packet> {
+    let mut parser = Parser {
+        src,
+        tokens: tokenize(src)?,
+        next: 0,
+    };
+
+    let packet = parser.comparison()?;
+    if let Some(token) = parser.peek() {
+        return Err(parser.error(format!("expected the end, found {}", token.describe())));
+    }
+    Ok(packet)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{encode, parse_input};
+
+    fn evaluate(src: &str) -> String {
+        let hex = encode(&compile(src).unwrap()).unwrap();
+        parse_input(&hex).unwrap().apply().unwrap().to_string()
+    }
+
+    #[test]
+    fn expressions_evaluate() {
+        assert_eq!(evaluate("max(1, 2*3, 4 < 5)"), "6");
+        assert_eq!(evaluate("1 + 2 * 3"), "7");
+        assert_eq!(evaluate("(1 + 2) * 3"), "9");
+        assert_eq!(evaluate("min(7, 4 + 4, 5) == 5"), "1");
+        assert_eq!(evaluate("2 * 3 > 7"), "0");
+        assert_eq!(evaluate("sum(2) + product(3)"), "5");
+        assert_eq!(evaluate("2021"), "2021");
+        assert_eq!(
+            evaluate("99999999999999999999999 * 99999999999999999999999"),
+            "9999999999999999999999800000000000000000000001"
+        );
+    }
+
+    #[test]
+    fn operators_are_flattened() {
+        assert_eq!(
+            compile("1 + 2 + 3").unwrap(),
+            compile("sum(1, 2, 3)").unwrap()
+        );
+    }
+
+    #[test]
+    fn bad_expressions_are_reported() {
+        let err = |src| compile(src).unwrap_err().to_string();
+        assert_eq!(
+            err("max(1, 2"),
+            "line 1, column 9: expected ',' or ')', found the end"
+        );
+        assert_eq!(err("1 - 2"), "line 1, column 3: unexpected '-'");
+        assert_eq!(
+            err("1 < 2 < 3"),
+            "line 1, column 7: comparisons can't be chained, add parentheses"
+        );
+        assert_eq!(
+            err("avg(1, 2)"),
+            "line 1, column 1: unknown function \"avg\", expected sum, product, min or max"
+        );
+        assert_eq!(
+            err("min()"),
+            "line 1, column 5: min needs at least one operand"
+        );
+        assert_eq!(
+            err("sum()"),
+            "line 1, column 5: sum needs at least one operand"
+        );
+        assert_eq!(
+            err("1 2"),
+            "line 1, column 3: expected the end, found number 2"
+        );
+        assert_eq!(
+            err("(1 + )"),
+            "line 1, column 6: expected a value, found ')'"
+        );
+    }
+}
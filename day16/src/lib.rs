@@ -0,0 +1,582 @@
+use anyhow::{anyhow, Result};
+use aoc_common::{Answer, Solution};
+pub use num_bigint::BigUint;
+
+mod reader;
+pub use reader::BitReader;
+
+mod encode;
+pub use encode::{encode, encode_with, LengthType};
+
+mod expr;
+pub use expr::compile;
+
+mod disasm;
+pub use disasm::disassemble;
+
+mod optimise;
+pub use optimise::optimise;
+
+mod visit;
+pub use visit::{walk, Count, Depth, Evaluate, Histogram, VersionSum, Visitor};
+
+/// The type id reserved for literal packets; every other id is an operator.
+const LITERAL_TYPE_ID: usize = 4;
+
+/// The fewest bits a packet can take up: a literal with a single group.
+const MIN_PACKET_BITS: usize = 11;
+
+/// How many packets deep a transmission may nest, counting the outermost.
+/// Decoding goes a call deeper for each packet, so this keeps hostile input
+/// from overflowing the stack.
+pub const MAX_DEPTH: usize = 256;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Packet {
+    pub kind: PacketType,
+    pub version: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PacketType {
+    Literal(BigUint),
+    Operator(OpKind, Vec<Packet>),
+}
+
+impl Drop for Packet {
+    /// Takes the tree apart onto a list rather than recursing, so dropping a
+    /// deep one can't overflow the stack.
+    fn drop(&mut self) {
+        let PacketType::Operator(_, packets) = &mut self.kind else {
+            return;
+        };
+        let mut rest = std::mem::take(packets);
+        while let Some(mut packet) = rest.pop() {
+            if let PacketType::Operator(_, packets) = &mut packet.kind {
+                rest.append(packets);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum OpKind {
+    Sum,
+    Product,
+    Minimum,
+    Maximum,
+    GreaterThan,
+    LessThan,
+    EqualTo,
+}
+
+impl TryFrom<usize> for OpKind {
+    type Error = String;
+
+    fn try_from(type_id: usize) -> Result<Self, Self::Error> {
+        Ok(match type_id {
+            0 => OpKind::Sum,
+            1 => OpKind::Product,
+            2 => OpKind::Minimum,
+            3 => OpKind::Maximum,
+            5 => OpKind::GreaterThan,
+            6 => OpKind::LessThan,
+            7 => OpKind::EqualTo,
+            LITERAL_TYPE_ID => return Err("type id 4 is a literal, not an operator".to_owned()),
+            _ => return Err(format!("type id {} doesn't fit in 3 bits", type_id)),
+        })
+    }
+}
+
+impl OpKind {
+    pub fn type_id(&self) -> usize {
+        match self {
+            OpKind::Sum => 0,
+            OpKind::Product => 1,
+            OpKind::Minimum => 2,
+            OpKind::Maximum => 3,
+            OpKind::GreaterThan => 5,
+            OpKind::LessThan => 6,
+            OpKind::EqualTo => 7,
+        }
+    }
+
+    /// The number of sub-packets this operator can be applied to. Every
+    /// operator needs at least one.
+    fn arity(&self) -> (usize, Option<usize>) {
+        match self {
+            OpKind::Sum | OpKind::Product | OpKind::Minimum | OpKind::Maximum => (1, None),
+            OpKind::GreaterThan | OpKind::LessThan | OpKind::EqualTo => (2, Some(2)),
+        }
+    }
+
+    fn check_arity(&self, packets: usize) -> Result<(), String> {
+        let (min, max) = self.arity();
+        if packets < min || max.is_some_and(|max| packets > max) {
+            return Err(format!("{:?} can't apply to {} sub-packets", self, packets));
+        }
+        Ok(())
+    }
+
+    /// Applies the operator to the values of its sub-packets.
+    fn apply(&self, vals: Vec<BigUint>) -> Result<BigUint> {
+        self.check_arity(vals.len()).map_err(|e| anyhow!(e))?;
+
+        Ok(match self {
+            OpKind::Sum => vals.into_iter().sum(),
+            OpKind::Product => vals.into_iter().product(),
+            OpKind::Minimum => vals.into_iter().min().unwrap_or_default(),
+            OpKind::Maximum => vals.into_iter().max().unwrap_or_default(),
+            OpKind::GreaterThan => BigUint::from(vals[0] > vals[1]),
+            OpKind::LessThan => BigUint::from(vals[0] < vals[1]),
+            OpKind::EqualTo => BigUint::from(vals[0] == vals[1]),
+        })
+    }
+}
+
+impl Packet {
+    /// Evaluates the packet, failing if an operator has the wrong number of
+    /// sub-packets, which only a leniently decoded packet can.
+    pub fn apply(&self) -> Result<BigUint> {
+        walk(self, &mut Evaluate)
+    }
+
+    /// How many packets deep the tree goes, counting this one.
+    pub fn depth(&self) -> usize {
+        walk(self, &mut Depth)
+    }
+
+    /// How many packets there are, counting this one.
+    pub fn count(&self) -> usize {
+        walk(self, &mut Count)
+    }
+}
+
+/// How forgiving decoding is about transmissions that bend the rules.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Reject anything the spec doesn't allow, pointing at the bit it starts at.
+    #[default]
+    Strict,
+    /// Decode whatever can be decoded: operators may have any number of
+    /// sub-packets, bits too few to be a packet may follow an operator's
+    /// sub-packets, and anything after the outermost packet is ignored.
+    Lenient,
+}
+
+/// The sub-packets of an operator starting at bit `start`, once its type id
+/// is read, where the operator is `depth` packets deep.
+fn take_operator(
+    reader: &mut BitReader,
+    start: usize,
+    opkind: OpKind,
+    mode: Mode,
+    depth: usize,
+) -> Result<PacketType> {
+    let packets = match reader.read(1, "length type id")? {
+        1 => {
+            let num = reader.read(11, "sub-packet count")?;
+            (0..num)
+                .map(|_| take_packet(reader, mode, depth + 1))
+                .collect::<Result<Vec<_>>>()?
+        }
+        _ => {
+            let num = reader.read(15, "sub-packet length")?;
+            let mut sub = reader.take(num, "sub-packets")?;
+            let mut packets = vec![];
+
+            while sub.remaining() >= MIN_PACKET_BITS {
+                packets.push(take_packet(&mut sub, mode, depth + 1)?);
+            }
+            if sub.remaining() > 0 && mode == Mode::Strict {
+                return Err(reader.error(
+                    sub.position(),
+                    format!(
+                        "{} bits left over after the sub-packets, too few for another",
+                        sub.remaining()
+                    ),
+                ));
+            }
+            packets
+        }
+    };
+
+    if mode == Mode::Strict {
+        opkind
+            .check_arity(packets.len())
+            .map_err(|e| reader.error(start, e))?;
+    }
+
+    Ok(PacketType::Operator(opkind, packets))
+}
+
+fn take_message(reader: &mut BitReader) -> Result<PacketType> {
+    let mut message = vec![];
+    loop {
+        let group = reader.read(5, "literal group")?;
+        message.push((group & 0xf) as u8);
+        if group >> 4 == 0 {
+            break;
+        }
+    }
+
+    // every group is one hex digit, so this can't fail
+    let value = BigUint::from_radix_be(&message, 16).unwrap_or_default();
+    Ok(PacketType::Literal(value))
+}
+
+/// The packet starting at the reader's position, which is `depth` packets deep.
+fn take_packet(reader: &mut BitReader, mode: Mode, depth: usize) -> Result<Packet> {
+    let start = reader.position();
+    if depth > MAX_DEPTH {
+        return Err(reader.error(
+            start,
+            format!("packets can't nest more than {} deep", MAX_DEPTH),
+        ));
+    }
+    let version = reader.read(3, "version")?;
+    let kind = match reader.read(3, "type id")? {
+        LITERAL_TYPE_ID => take_message(reader)?,
+        type_id => {
+            let opkind = OpKind::try_from(type_id).map_err(|e| reader.error(start, e))?;
+            take_operator(reader, start, opkind, mode, depth)?
+        }
+    };
+    Ok(Packet { kind, version })
+}
+
+/// Decodes a transmission, holding it to the rules as tightly as `mode` says.
+/// Either way, packets nested more than [`MAX_DEPTH`] deep are rejected.
+pub fn decode(input: &str, mode: Mode) -> Result<Packet> {
+    let mut reader = BitReader::new(input)?;
+    let packet = take_packet(&mut reader, mode, 1)?;
+
+    if mode == Mode::Strict {
+        while reader.remaining() > 0 {
+            let position = reader.position();
+            if reader.read(1, "padding")? != 0 {
+                return Err(reader.error(position, "expected only zero padding after the packet"));
+            }
+        }
+    }
+    Ok(packet)
+}
+
+/// Decodes a transmission in [`Mode::Strict`].
+pub fn parse_input(input: &str) -> Result<Packet> {
+    decode(input, Mode::Strict)
+}
+
+pub fn version_sum(packet: &Packet) -> usize {
+    walk(packet, &mut VersionSum)
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Packet;
+    type Part1 = usize;
+    type Part2 = Answer;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(packet: &Self::Input) -> Result<usize> {
+        Ok(version_sum(packet))
+    }
+
+    fn part2(packet: &Self::Input) -> Result<Answer> {
+        // answers too big for a u128 are given as text
+        Ok(match u128::try_from(packet.apply()?) {
+            Ok(value) => value.into(),
+            Err(e) => e.into_original().to_string().into(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_literal() {
+        let input = "D2FE28";
+        let packet = parse_input(input).unwrap();
+        assert_eq!(
+            packet,
+            Packet {
+                version: 6,
+                kind: PacketType::Literal(2021u32.into())
+            }
+        )
+    }
+
+    #[test]
+    fn test_operator_packet_number() {
+        let input = "EE00D40C823060";
+        let packet = parse_input(input).unwrap();
+        assert_eq!(
+            packet,
+            Packet {
+                version: 7,
+                kind: PacketType::Operator(
+                    OpKind::Maximum,
+                    vec![
+                        Packet {
+                            kind: PacketType::Literal(1u32.into()),
+                            version: 2,
+                        },
+                        Packet {
+                            kind: PacketType::Literal(2u32.into()),
+                            version: 4,
+                        },
+                        Packet {
+                            kind: PacketType::Literal(3u32.into()),
+                            version: 1,
+                        },
+                    ]
+                )
+            }
+        )
+    }
+
+    #[test]
+    fn test_operator_bit_number() {
+        let input = "38006F45291200";
+        let packet = parse_input(input).unwrap();
+        assert_eq!(
+            packet,
+            Packet {
+                version: 1,
+                kind: PacketType::Operator(
+                    OpKind::LessThan,
+                    vec![
+                        Packet {
+                            kind: PacketType::Literal(10u32.into()),
+                            version: 6,
+                        },
+                        Packet {
+                            kind: PacketType::Literal(20u32.into()),
+                            version: 2,
+                        },
+                    ]
+                )
+            }
+        )
+    }
+
+    #[test]
+    fn test_operator_sum() {
+        fn input_output(input: &str, output: u32) {
+            let packet = parse_input(input).unwrap();
+            assert_eq!(packet.apply().unwrap(), output.into());
+        }
+
+        input_output("C200B40A82", 3);
+        input_output("04005AC33890", 54);
+        input_output("880086C3E88112", 7);
+        input_output("CE00C43D881120", 9);
+        input_output("D8005AC2A8F0", 1);
+        input_output("F600BC2D8F", 0);
+        input_output("9C005AC2F8F0", 0);
+        input_output("9C0141080250320F1802104A08", 1);
+    }
+
+    #[test]
+    fn huge_values_evaluate() {
+        // a 128 bit literal times 2^64
+        let hex = encode(
+            &compile("340282366920938463463374607431768211455 * 18446744073709551616").unwrap(),
+        )
+        .unwrap();
+        let packet = parse_input(&hex).unwrap();
+        assert_eq!(
+            Day16::part2(&packet).unwrap(),
+            Answer::Text("6277101735386680763835789423207666416083908700390324961280".to_owned())
+        );
+
+        let packet = parse_input(&encode(&compile("2 * 3").unwrap()).unwrap()).unwrap();
+        assert_eq!(Day16::part2(&packet).unwrap(), Answer::Int(6));
+    }
+
+    #[test]
+    fn bad_transmissions_are_reported() {
+        let err = parse_input("D2FE2G").unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 6: 'G' is not a hex digit");
+
+        // a literal whose last group is cut short
+        let err = parse_input("D2FE").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 5: bit 16: expected 5 bits of literal group, found 0"
+        );
+
+        // "less than" with three sub-packets
+        let err = parse_input("3A00D40C823060").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: bit 0: LessThan can't apply to 3 sub-packets"
+        );
+
+        // a sub-packet length running past the end
+        let err = parse_input("38FF").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 2: bit 7: expected 15 bits of sub-packet length, found 9"
+        );
+    }
+
+    #[test]
+    fn strict_decoding_rejects_what_lenient_allows() {
+        let strict = |hex| decode(hex, Mode::Strict).unwrap_err().to_string();
+        let lenient = |hex| decode(hex, Mode::Lenient).unwrap();
+
+        // the last bit of padding is set
+        assert_eq!(
+            strict("D2FE29"),
+            "line 1, column 6: bit 23: expected only zero padding after the packet"
+        );
+        assert_eq!(lenient("D2FE29"), parse_input("D2FE28").unwrap());
+
+        // three more bits of sub-packets than the two literals take up
+        assert_eq!(
+            strict("38007B45291200"),
+            "line 1, column 13: bit 49: 3 bits left over after the sub-packets, too few for another"
+        );
+        assert_eq!(
+            lenient("38007B45291200"),
+            parse_input("38006F45291200").unwrap()
+        );
+
+        // a maximum of nothing
+        assert_eq!(
+            strict("0E000"),
+            "line 1, column 1: bit 0: Maximum can't apply to 0 sub-packets"
+        );
+        assert_eq!(
+            lenient("0E000").apply().unwrap_err().to_string(),
+            "Maximum can't apply to 0 sub-packets"
+        );
+
+        // a sum of nothing
+        assert_eq!(
+            strict("02000"),
+            "line 1, column 1: bit 0: Sum can't apply to 0 sub-packets"
+        );
+        assert_eq!(
+            lenient("02000").apply().unwrap_err().to_string(),
+            "Sum can't apply to 0 sub-packets"
+        );
+    }
+
+    #[test]
+    fn trees_are_measured() {
+        let packet = parse_input("9C0141080250320F1802104A08").unwrap();
+        assert_eq!(packet.depth(), 3);
+        assert_eq!(packet.count(), 7);
+        assert_eq!(version_sum(&packet), 20);
+
+        let mut histogram = Histogram::default();
+        walk(&packet, &mut histogram);
+        assert_eq!(
+            histogram.0.into_iter().collect::<Vec<_>>(),
+            [(OpKind::Sum, 1), (OpKind::Product, 1), (OpKind::EqualTo, 1)]
+        );
+
+        let literal = parse_input("D2FE28").unwrap();
+        assert_eq!((literal.depth(), literal.count()), (1, 1));
+    }
+
+    /// `sums` sums of a single sub-packet each, nested around a literal 1.
+    fn nested_sums(sums: usize) -> String {
+        let mut bits = "000000100000000001".repeat(sums) + "00010000001";
+        while !bits.len().is_multiple_of(4) {
+            bits.push('0');
+        }
+        bits.as_bytes()
+            .chunks(4)
+            .map(|digit| {
+                let digit = std::str::from_utf8(digit).unwrap();
+                format!("{:X}", u8::from_str_radix(digit, 2).unwrap())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn nesting_is_limited() {
+        let packet = parse_input(&nested_sums(MAX_DEPTH - 1)).unwrap();
+        assert_eq!(packet.depth(), MAX_DEPTH);
+        assert_eq!(packet.apply().unwrap(), 1u32.into());
+
+        // the literal inside the innermost sum
+        let position = MAX_DEPTH * 18;
+        assert_eq!(
+            parse_input(&nested_sums(MAX_DEPTH))
+                .unwrap_err()
+                .to_string(),
+            format!(
+                "line 1, column {}: bit {}: packets can't nest more than 256 deep",
+                position / 4 + 1,
+                position
+            )
+        );
+    }
+
+    #[test]
+    fn deep_trees_are_walked_and_dropped() {
+        let mut packet = Packet {
+            version: 1,
+            kind: PacketType::Literal(2u32.into()),
+        };
+        for _ in 0..100_000 {
+            packet = Packet {
+                version: 1,
+                kind: PacketType::Operator(OpKind::Maximum, vec![packet]),
+            };
+        }
+
+        assert_eq!(packet.apply().unwrap(), 2u32.into());
+        assert_eq!(packet.depth(), 100_001);
+        assert_eq!(version_sum(&packet), 100_001);
+    }
+
+    #[test]
+    fn deeply_nested_transmissions_fail_cleanly() {
+        // about 90 KB of hex, which used to overflow the stack
+        let hex = nested_sums(20_000);
+        for mode in [Mode::Strict, Mode::Lenient] {
+            assert_eq!(
+                decode(&hex, mode).unwrap_err().to_string(),
+                "line 1, column 1153: bit 4608: packets can't nest more than 256 deep"
+            );
+        }
+        assert!(disassemble(&hex).is_err());
+    }
+
+    /// Decodes `input` every way there is, which must fail cleanly if at all.
+    fn decode_every_way(input: &str) {
+        for mode in [Mode::Strict, Mode::Lenient] {
+            if let Ok(packet) = decode(input, mode) {
+                let _ = packet.apply();
+            }
+        }
+        let _ = disassemble(input);
+    }
+
+    proptest! {
+        #[test]
+        fn any_hex_decodes_without_panicking(input in "[0-9A-F]{0,40}|\\PC{0,20}") {
+            decode_every_way(&input);
+        }
+
+        #[test]
+        fn nested_hex_decodes_without_panicking(sums in 0..2 * MAX_DEPTH, cut in 0..=1000usize) {
+            // deep transmissions, cut short anywhere
+            let hex = nested_sums(sums);
+            decode_every_way(&hex[..hex.len() * cut / 1000]);
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use anyhow::{anyhow, Result};
+
+use crate::{walk, BigUint, OpKind, Packet, PacketType, Visitor};
+
+fn literal(version: usize, value: BigUint) -> Packet {
+    Packet {
+        version,
+        kind: PacketType::Literal(value),
+    }
+}
+
+fn value(packet: &Packet) -> Option<&BigUint> {
+    match &packet.kind {
+        PacketType::Literal(value) => Some(value),
+        PacketType::Operator(..) => None,
+    }
+}
+
+/// Rebuilds each operator out of its already simplified sub-packets.
+struct Optimise;
+
+impl Visitor for Optimise {
+    type Output = Result<Packet>;
+
+    fn leave(&mut self, packet: &Packet, _: usize, children: Vec<Result<Packet>>) -> Self::Output {
+        let opkind = match &packet.kind {
+            PacketType::Literal(_) => return Ok(packet.clone()),
+            PacketType::Operator(opkind, _) => *opkind,
+        };
+        let version = packet.version;
+        let children = children.into_iter().collect::<Result<Vec<_>>>()?;
+        opkind.check_arity(children.len()).map_err(|e| anyhow!(e))?;
+
+        let identity = match opkind {
+            OpKind::Sum => Some(BigUint::from(0u32)),
+            OpKind::Product => Some(BigUint::from(1u32)),
+            OpKind::Minimum | OpKind::Maximum => None,
+            OpKind::GreaterThan | OpKind::LessThan | OpKind::EqualTo => {
+                let values = children.iter().map(value).collect::<Option<Vec<_>>>();
+                return Ok(match values {
+                    Some(values) => literal(
+                        version,
+                        opkind.apply(values.into_iter().cloned().collect())?,
+                    ),
+                    None => Packet {
+                        version,
+                        kind: PacketType::Operator(opkind, children),
+                    },
+                });
+            }
+        };
+
+        let mut packets = vec![];
+        for mut child in children {
+            match &mut child.kind {
+                PacketType::Operator(inner, nested) if *inner == opkind => packets.append(nested),
+                PacketType::Literal(value) if Some(&*value) == identity.as_ref() => {}
+                _ => packets.push(child),
+            }
+        }
+
+        Ok(match packets.len() {
+            // only a sum or product can lose all its operands
+            0 => literal(version, identity.unwrap_or_default()),
+            1 => packets.remove(0),
+            _ => Packet {
+                version,
+                kind: PacketType::Operator(opkind, packets),
+            },
+        })
+    }
+}
+
+/// Rewrites `packet` into a smaller one that evaluates to the same value:
+/// nested sums, products, minimums and maximums are flattened, zeroes are
+/// dropped from sums and ones from products, operators left with a single
+/// operand are replaced by it, and comparisons of two literals become the
+/// literal they evaluate to.
+///
+/// Versions aren't kept: a packet that replaces others takes the version of
+/// the outermost one it replaces. Fails if any operator has the wrong number
+/// of sub-packets.
+pub fn optimise(packet: &Packet) -> Result<Packet> {
+    walk(packet, &mut Optimise)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{compile, encode, parse_input};
+
+    /// Optimises the expression and checks the result evaluates to the same
+    /// once encoded and decoded again, without getting any longer.
+    fn optimised(src: &str) -> Packet {
+        let packet = compile(src).unwrap();
+        let optimised = optimise(&packet).unwrap();
+
+        let before = encode(&packet).unwrap();
+        let after = encode(&optimised).unwrap();
+        assert!(after.len() <= before.len(), "{} got longer", src);
+        assert_eq!(
+            parse_input(&after).unwrap().apply().unwrap(),
+            parse_input(&before).unwrap().apply().unwrap(),
+            "{} changed value",
+            src
+        );
+        optimised
+    }
+
+    #[test]
+    fn operators_are_simplified() {
+        let cases = [
+            ("1 + (2 + 3) + 4", "1 + 2 + 3 + 4"),
+            ("2 * (3 * 4)", "2 * 3 * 4"),
+            ("max(1, max(2, 3))", "max(1, 2, 3)"),
+            ("min(max(1, 2), 3)", "min(max(1, 2), 3)"),
+            ("0 + 5 + 0", "5"),
+            ("1 * 5 * (2 < 1 + 0)", "5 * 0"),
+            ("1 * 5 * (2 < 1 + 2)", "5 * (2 < 1 + 2)"),
+            ("(1 < 2) * 7", "7"),
+            ("(3 == 3) + (4 > 5) + 6", "1 + 6"),
+            ("max(2 + 3)", "2 + 3"),
+            ("sum(0, 0)", "0"),
+            ("product(1) + sum(0)", "1"),
+            ("2 > 1 + 1", "2 > 1 + 1"),
+        ];
+
+        for (src, expected) in cases {
+            assert_eq!(optimised(src), compile(expected).unwrap(), "{}", src);
+        }
+    }
+
+    #[test]
+    fn examples_keep_their_value() {
+        for hex in [
+            "C200B40A82",
+            "04005AC33890",
+            "880086C3E88112",
+            "CE00C43D881120",
+            "D8005AC2A8F0",
+            "F600BC2D8F",
+            "9C005AC2F8F0",
+            "9C0141080250320F1802104A08",
+            "A0016C880162017C3686B18A3D4780",
+        ] {
+            let packet = parse_input(hex).unwrap();
+            let encoded = encode(&optimise(&packet).unwrap()).unwrap();
+            assert!(encoded.len() <= hex.len(), "{} got longer", hex);
+            assert_eq!(
+                parse_input(&encoded).unwrap().apply().unwrap(),
+                packet.apply().unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn malformed_packets_are_rejected() {
+        let packet = crate::decode("0E000", crate::Mode::Lenient).unwrap();
+        assert_eq!(
+            optimise(&packet).unwrap_err().to_string(),
+            "Maximum can't apply to 0 sub-packets"
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use std::fmt::Display;
+
+use anyhow::{Error, Result};
+use aoc_common::ParseError;
+
+/// Reads a transmission's bits straight out of its hex digits, a field at a time.
+#[derive(Debug, Clone)]
+pub struct BitReader<'a> {
+    hex: &'a [u8],
+    position: usize,
+    end: usize,
+}
+
+impl<'a> BitReader<'a> {
+    /// A reader over the hex digits of `input`, ignoring trailing whitespace.
+    ///
+    /// Digits are only checked as they're read, except that anything outside
+    /// ASCII is rejected up front so errors can point at whole characters.
+    pub fn new(input: &'a str) -> Result<Self> {
+        let input = input.trim_end();
+        if let Some((i, c)) = input.chars().enumerate().find(|(_, c)| !c.is_ascii()) {
+            return Err(ParseError::new(1, i + 1, format!("{:?} is not a hex digit", c)).into());
+        }
+
+        Ok(Self {
+            hex: input.as_bytes(),
+            position: 0,
+            end: input.len() * 4,
+        })
+    }
+
+    /// How many bits have been read from the start of the transmission.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// How many bits are left before the end of the transmission, or of the
+    /// sub-reader this is.
+    pub fn remaining(&self) -> usize {
+        self.end - self.position
+    }
+
+    /// An error about the bit at `position`.
+    pub fn error(&self, position: usize, message: impl Display) -> Error {
+        ParseError::new(
+            1,
+            position / 4 + 1,
+            format!("bit {}: {}", position, message),
+        )
+        .into()
+    }
+
+    fn expect(&self, n: usize, what: &str) -> Result<()> {
+        if n > self.remaining() {
+            return Err(self.error(
+                self.position,
+                format!(
+                    "expected {} bits of {}, found {}",
+                    n,
+                    what,
+                    self.remaining()
+                ),
+            ));
+        }
+        Ok(())
+    }
+
+    fn digit(&self, i: usize) -> Result<u8> {
+        let c = self.hex[i] as char;
+        c.to_digit(16)
+            .map(|digit| digit as u8)
+            .ok_or_else(|| ParseError::new(1, i + 1, format!("{:?} is not a hex digit", c)).into())
+    }
+
+    /// Reads an `n` bit field, most significant bit first, where `what` says
+    /// what the field is for if the transmission ends too soon.
+    pub fn read(&mut self, n: usize, what: &str) -> Result<usize> {
+        debug_assert!(n <= usize::BITS as usize);
+        self.expect(n, what)?;
+
+        let mut value = 0;
+        for _ in 0..n {
+            let digit = self.digit(self.position / 4)?;
+            let bit = digit >> (3 - self.position % 4) & 1;
+            value = value << 1 | bit as usize;
+            self.position += 1;
+        }
+        Ok(value)
+    }
+
+    /// Splits off a reader over the next `n` bits and skips past them.
+    pub fn take(&mut self, n: usize, what: &str) -> Result<BitReader<'a>> {
+        self.expect(n, what)?;
+
+        let sub = Self {
+            end: self.position + n,
+            ..self.clone()
+        };
+        self.position += n;
+        Ok(sub)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn fields_span_digits() {
+        let mut reader = BitReader::new("D2FE28\n").unwrap();
+        assert_eq!(reader.read(3, "version").unwrap(), 6);
+        assert_eq!(reader.read(3, "type id").unwrap(), 4);
+        assert_eq!(reader.read(5, "group").unwrap(), 0b10111);
+        assert_eq!(reader.position(), 11);
+        assert_eq!(reader.remaining(), 13);
+    }
+
+    #[test]
+    fn sub_readers_stop_early() {
+        let mut reader = BitReader::new("FF").unwrap();
+        let mut sub = reader.take(3, "sub-packets").unwrap();
+        assert_eq!(reader.position(), 3);
+        assert_eq!(sub.read(2, "a").unwrap(), 3);
+        assert_eq!(
+            sub.read(2, "b").unwrap_err().to_string(),
+            "line 1, column 1: bit 2: expected 2 bits of b, found 1"
+        );
+    }
+
+    #[test]
+    fn bad_digits_are_found_when_read() {
+        let mut reader = BitReader::new("1x").unwrap();
+        assert_eq!(reader.read(4, "a").unwrap(), 1);
+        assert_eq!(
+            reader.read(1, "b").unwrap_err().to_string(),
+            "line 1, column 2: 'x' is not a hex digit"
+        );
+        assert_eq!(
+            BitReader::new("1é").unwrap_err().to_string(),
+            "line 1, column 2: 'é' is not a hex digit"
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+
+use crate::{BigUint, OpKind, Packet, PacketType};
+
+/// An analysis over a packet tree, driven by [`walk`].
+///
+/// `enter` sees each packet before its sub-packets and `leave` after them,
+/// getting what `leave` gave for each of its sub-packets in order.
+pub trait Visitor {
+    type Output;
+
+    fn enter(&mut self, _packet: &Packet, _depth: usize) {}
+
+    fn leave(&mut self, packet: &Packet, depth: usize, children: Vec<Self::Output>)
+        -> Self::Output;
+}
+
+/// A packet being walked, with the sub-packets still to visit and what
+/// `leave` gave for those already visited.
+struct Frame<'a, O> {
+    packet: &'a Packet,
+    rest: std::slice::Iter<'a, Packet>,
+    children: Vec<O>,
+}
+
+impl<'a, O> Frame<'a, O> {
+    fn new(packet: &'a Packet) -> Self {
+        let rest = match &packet.kind {
+            PacketType::Literal(_) => [].iter(),
+            PacketType::Operator(_, packets) => packets.iter(),
+        };
+        Self {
+            packet,
+            rest,
+            children: vec![],
+        }
+    }
+}
+
+/// Visits every packet in `packet`, the outermost at depth 0, and returns
+/// what `leave` gave for it.
+///
+/// The packets still being walked are kept on a list rather than the call
+/// stack, so trees of any depth can be walked.
+pub fn walk<V: Visitor>(packet: &Packet, visitor: &mut V) -> V::Output {
+    let mut parents: Vec<Frame<V::Output>> = vec![];
+    let mut frame = Frame::new(packet);
+    visitor.enter(packet, 0);
+
+    loop {
+        if let Some(child) = frame.rest.next() {
+            visitor.enter(child, parents.len() + 1);
+            parents.push(std::mem::replace(&mut frame, Frame::new(child)));
+            continue;
+        }
+
+        let output = visitor.leave(frame.packet, parents.len(), frame.children);
+        match parents.pop() {
+            Some(parent) => {
+                frame = parent;
+                frame.children.push(output);
+            }
+            None => return output,
+        }
+    }
+}
+
+/// The sum of every packet's version.
+pub struct VersionSum;
+
+impl Visitor for VersionSum {
+    type Output = usize;
+
+    fn leave(&mut self, packet: &Packet, _: usize, children: Vec<usize>) -> usize {
+        packet.version + children.into_iter().sum::<usize>()
+    }
+}
+
+/// What a packet evaluates to.
+pub struct Evaluate;
+
+impl Visitor for Evaluate {
+    type Output = Result<BigUint>;
+
+    fn leave(&mut self, packet: &Packet, _: usize, children: Vec<Result<BigUint>>) -> Self::Output {
+        match &packet.kind {
+            PacketType::Literal(value) => Ok(value.clone()),
+            PacketType::Operator(opkind, _) => {
+                opkind.apply(children.into_iter().collect::<Result<_>>()?)
+            }
+        }
+    }
+}
+
+/// How many packets deep the tree goes, counting the outermost.
+pub struct Depth;
+
+impl Visitor for Depth {
+    type Output = usize;
+
+    fn leave(&mut self, _: &Packet, _: usize, children: Vec<usize>) -> usize {
+        1 + children.into_iter().max().unwrap_or(0)
+    }
+}
+
+/// How many packets there are, counting the outermost.
+pub struct Count;
+
+impl Visitor for Count {
+    type Output = usize;
+
+    fn leave(&mut self, _: &Packet, _: usize, children: Vec<usize>) -> usize {
+        1 + children.into_iter().sum::<usize>()
+    }
+}
+
+/// How many times each operator is used.
+#[derive(Debug, Default)]
+pub struct Histogram(pub BTreeMap<OpKind, usize>);
+
+impl Visitor for Histogram {
+    type Output = ();
+
+    fn enter(&mut self, packet: &Packet, _: usize) {
+        if let PacketType::Operator(opkind, _) = &packet.kind {
+            *self.0.entry(*opkind).or_default() += 1;
+        }
+    }
+
+    fn leave(&mut self, _: &Packet, _: usize, _: Vec<()>) {}
+}
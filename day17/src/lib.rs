@@ -0,0 +1,414 @@
+use std::{collections::HashMap, ops::RangeInclusive};
+
+use anyhow::Result;
+use aoc_common::{ParseError, Solution};
+
+fn distance_to_time(distance: i32, cache: &mut HashMap<i32, Option<i32>>) -> Option<i32> {
+    if let Some(val) = cache.get(&distance) {
+        *val
+    } else {
+        let mut distance = distance;
+        let mut time = 0;
+        for sub in 1.. {
+            if distance >= sub {
+                distance -= sub;
+                time += 1;
+            } else {
+                break;
+            }
+        }
+
+        let val = if distance == 0 { Some(time) } else { None };
+
+        cache.insert(distance, val);
+        val
+    }
+}
+#[test]
+fn distance_to_time_works() {
+    let mut cache = HashMap::new();
+    assert_eq!(distance_to_time(5050, &mut cache), Some(100))
+}
+
+fn velocity_to_distance(vel: i32) -> i32 {
+    (1 + vel) * (vel / 2) + (vel % 2 * (vel / 2 + 1))
+}
+
+#[test]
+fn velocity_to_distance_works() {
+    assert_eq!(velocity_to_distance(100), 5050);
+    assert_eq!(velocity_to_distance(101), 5151);
+}
+
+fn y_max(y_range: RangeInclusive<i32>) -> i32 {
+    // if you shoot it through it will go through it again
+    // the largest step you can take is one ie. taking multiple steps will result in a smaller max
+    // therefore the way to work out the max is the largest step you can take for the difference
+    // between the start at 0 and the end
+    // upper bound is the largest step between start and end
+    let min = &y_range.clone().min().unwrap_or(0);
+    let velocity = if min < &0 {
+        min.abs() - 1
+    } else {
+        y_range.max().unwrap_or(0)
+    };
+    velocity_to_distance(velocity)
+}
+
+#[test]
+fn y_max_works() {
+    assert_eq!(y_max(-10..=-5i32), 45)
+}
+
+fn part1(y_range: RangeInclusive<i32>) -> i32 {
+    y_max(y_range)
+}
+
+#[test]
+fn part1_works() {
+    assert_eq!(part1(-10..=-5i32), 45)
+}
+
+fn distance_travellable_in(mut vel: i32, mut distance: i32) -> Option<usize> {
+    let mut time = 0usize;
+    while distance > 0 && vel > 0 {
+        distance -= vel;
+        vel -= 1;
+        time += 1;
+    }
+
+    if distance == 0 {
+        Some(time)
+    } else {
+        None
+    }
+}
+
+#[test]
+fn distance_travellable_in_works() {
+    assert_eq!(distance_travellable_in(6, 11), Some(2))
+}
+
+fn combo_count(
+    distance: i32,
+    cache: &mut HashMap<i32, Vec<(usize, i32)>>,
+) -> (i32, Vec<(usize, i32)>) {
+    if let Some(count) = cache.get(&distance) {
+        (distance, count.clone())
+    } else {
+        let mut combos = vec![(1, distance)];
+        let mut dist_cache = HashMap::new();
+        let mut hits_zero_vel = false;
+        if distance_to_time(distance, &mut dist_cache).is_some() {
+            hits_zero_vel = true;
+        }
+
+        for vel in (0..=(distance / 2 + 1)).rev() {
+            if let Some(time) = distance_travellable_in(vel, distance) {
+                combos.push((time, vel))
+            }
+        }
+        if hits_zero_vel {
+            let last = combos.last().unwrap();
+            let vel = last.1;
+            let time = last.0;
+            for n in 1..1000 {
+                combos.push((time + n, vel));
+            }
+        }
+
+        cache.insert(distance, combos.clone());
+        (distance, combos)
+    }
+}
+
+#[test]
+fn combo_count_works() {
+    let mut cache = HashMap::new();
+    assert_eq!(combo_count(11, &mut cache), (11, vec![(1, 11), (2, 6)]))
+}
+
+fn y_at_t(mut vel: i32, t: usize) -> i32 {
+    let mut distance = 0;
+
+    for _ in 0..t {
+        distance += vel;
+        vel -= 1;
+    }
+
+    distance
+}
+
+// cache numbers that are possible to reach
+// distance: vec[times]
+// (time, distance) bool
+fn vel_reachable(
+    time: usize,
+    y: i32,
+    cache: &mut HashMap<(usize, i32), Option<i32>>,
+) -> Option<i32> {
+    if time == 1 {
+        return Some(y);
+    }
+    if let Some(val) = cache.get(&(time, y)) {
+        *val
+    } else {
+        let val = ((y + 1)..y.abs()).find(|&vel| y_at_t(vel, time) == y);
+
+        cache.insert((time, y), val);
+
+        val
+    }
+}
+
+fn all_combos(x_range: RangeInclusive<i32>, y_range: RangeInclusive<i32>) -> Vec<(i32, i32)> {
+    // for every x point try every number from max to min? denominator effect (max/1 + n + n)
+    // get a list of times
+    // with list of times work out if y can hit it?
+    let mut x_cache: HashMap<i32, Vec<(usize, i32)>> = HashMap::new();
+    let mut y_cache: HashMap<(usize, i32), Option<i32>> = HashMap::new();
+
+    y_range
+        .flat_map(|y| {
+            x_range
+                .clone()
+                .flat_map(|x| {
+                    let (_, times) = combo_count(x, &mut x_cache);
+
+                    times
+                        .into_iter()
+                        .filter_map(|(time, x_vel)| {
+                            let reached = vel_reachable(time, y, &mut y_cache);
+
+                            reached.map(|y_vel| (x_vel, y_vel))
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>()
+}
+#[test]
+fn all_combos_test() {
+    let mut combos = vec![
+        (6, 0),
+        (6, 1),
+        (6, 2),
+        (6, 3),
+        (6, 4),
+        (6, 5),
+        (6, 6),
+        (6, 7),
+        (6, 8),
+        (6, 9),
+        (7, -1),
+        (7, 0),
+        (7, 1),
+        (7, 2),
+        (7, 3),
+        (7, 4),
+        (7, 5),
+        (7, 6),
+        (7, 7),
+        (7, 8),
+        (7, 9),
+        (8, -1),
+        (8, -2),
+        (8, 0),
+        (8, 1),
+        (9, -1),
+        (9, -2),
+        (9, 0),
+        (10, -1),
+        (10, -2),
+        (11, -1),
+        (11, -2),
+        (11, -3),
+        (11, -4),
+        (12, -2),
+        (12, -3),
+        (12, -4),
+        (13, -2),
+        (13, -3),
+        (13, -4),
+        (14, -2),
+        (14, -3),
+        (14, -4),
+        (15, -2),
+        (15, -3),
+        (15, -4),
+        (20, -10),
+        (20, -5),
+        (20, -6),
+        (20, -7),
+        (20, -8),
+        (20, -9),
+        (21, -10),
+        (21, -5),
+        (21, -6),
+        (21, -7),
+        (21, -8),
+        (21, -9),
+        (22, -10),
+        (22, -5),
+        (22, -6),
+        (22, -7),
+        (22, -8),
+        (22, -9),
+        (23, -10),
+        (23, -5),
+        (23, -6),
+        (23, -7),
+        (23, -8),
+        (23, -9),
+        (24, -10),
+        (24, -5),
+        (24, -6),
+        (24, -7),
+        (24, -8),
+        (24, -9),
+        (25, -10),
+        (25, -5),
+        (25, -6),
+        (25, -7),
+        (25, -8),
+        (25, -9),
+        (26, -10),
+        (26, -5),
+        (26, -6),
+        (26, -7),
+        (26, -8),
+        (26, -9),
+        (27, -10),
+        (27, -5),
+        (27, -6),
+        (27, -7),
+        (27, -8),
+        (27, -9),
+        (28, -10),
+        (28, -5),
+        (28, -6),
+        (28, -7),
+        (28, -8),
+        (28, -9),
+        (29, -10),
+        (29, -5),
+        (29, -6),
+        (29, -7),
+        (29, -8),
+        (29, -9),
+        (30, -10),
+        (30, -5),
+        (30, -6),
+        (30, -7),
+        (30, -8),
+        (30, -9),
+    ];
+    combos.sort();
+    let mut result = all_combos(20..=30i32, -10..=-5);
+    result.sort();
+    result.dedup();
+
+    assert_eq!(combos.len(), 112);
+    assert_eq!(result.len(), 112);
+    assert_eq!(combos, result);
+}
+
+fn part2(x_range: RangeInclusive<i32>, y_range: RangeInclusive<i32>) -> usize {
+    let mut combos = all_combos(x_range, y_range);
+    combos.sort();
+    combos.dedup();
+
+    combos.len()
+}
+
+#[test]
+fn part2_works() {
+    assert_eq!(part2(20..=30i32, -10..=-5), 112)
+}
+
+fn parse_input(input: &str) -> Result<(RangeInclusive<i32>, RangeInclusive<i32>)> {
+    let input = input.trim_end();
+    let parse_range = |s: &str, axis: &str| -> Result<RangeInclusive<i32>, ParseError> {
+        let range = s
+            .strip_prefix(axis)
+            .and_then(|s| s.strip_prefix('='))
+            .ok_or_else(|| {
+                ParseError::at(input, s, format!("expected `{}=<start>..<end>`", axis))
+            })?;
+        let (start, end) = range
+            .split_once("..")
+            .ok_or_else(|| ParseError::at(input, range, "expected `<start>..<end>`"))?;
+        let parse = |n: &str| {
+            n.parse::<i32>()
+                .map_err(|_| ParseError::at(input, n, format!("{:?} is not a position", n)))
+        };
+        let range = parse(start)?..=parse(end)?;
+        if range.is_empty() {
+            return Err(ParseError::at(input, s, "the range is empty"));
+        }
+        Ok(range)
+    };
+
+    let ranges = input
+        .strip_prefix("target area: ")
+        .ok_or_else(|| ParseError::at(input, input, "expected `target area: `"))?;
+    let (x, y) = ranges
+        .split_once(", ")
+        .ok_or_else(|| ParseError::at(input, ranges, "expected `x=<range>, y=<range>`"))?;
+
+    let (x_range, y_range) = (parse_range(x, "x")?, parse_range(y, "y")?);
+    if *x_range.start() <= 0 {
+        return Err(ParseError::at(input, x, "the target must be ahead of the probe").into());
+    }
+    if *y_range.end() >= 0 {
+        return Err(ParseError::at(input, y, "the target must be below the probe").into());
+    }
+    Ok((x_range, y_range))
+}
+
+#[test]
+fn parse_input_works() {
+    assert_eq!(
+        parse_input("target area: x=20..30, y=-10..-5\n").unwrap(),
+        (20..=30, -10..=-5)
+    );
+    assert_eq!(
+        parse_input("target area: y=-10..-5, x=20..30")
+            .unwrap_err()
+            .to_string(),
+        "line 1, column 14: expected `x=<start>..<end>`"
+    );
+    assert_eq!(
+        parse_input("target area: x=20..3O, y=-10..-5")
+            .unwrap_err()
+            .to_string(),
+        "line 1, column 20: \"3O\" is not a position"
+    );
+    assert_eq!(
+        parse_input("target area: x=20..30, y=-10..5")
+            .unwrap_err()
+            .to_string(),
+        "line 1, column 24: the target must be below the probe"
+    );
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = (RangeInclusive<i32>, RangeInclusive<i32>);
+    type Part1 = i32;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1((_, y_range): &Self::Input) -> Result<i32> {
+        Ok(part1(y_range.clone()))
+    }
+
+    fn part2((x_range, y_range): &Self::Input) -> Result<usize> {
+        Ok(part2(x_range.clone(), y_range.clone()))
+    }
+}
@@ -0,0 +1,902 @@
+use std::{cmp::Reverse, fmt, str::FromStr, thread};
+
+use anyhow::{anyhow, bail, Context, Result};
+use aoc_common::{parse::parse_lines, ParseError, Solution};
+
+/// The numbers that decide how snailfish arithmetic behaves. The default is
+/// the puzzle's.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SnailRules {
+    /// A pair nested inside this many others explodes.
+    explode_depth: usize,
+    /// A regular number this big or bigger splits.
+    split_threshold: i32,
+    /// What the left half of a pair is multiplied by in its magnitude.
+    left_weight: i32,
+    /// What the right half of a pair is multiplied by in its magnitude.
+    right_weight: i32,
+}
+
+impl SnailRules {
+    /// Rules where pairs nested inside `explode_depth` others explode,
+    /// numbers of `split_threshold` or more split, and a pair's magnitude is
+    /// `left_weight` times its left half's plus `right_weight` times its
+    /// right half's.
+    ///
+    /// Fails if `split_threshold` is below 2, since splitting 0 or 1 gives a
+    /// pair that holds the same number again and reduction never finishes.
+    pub fn new(
+        explode_depth: usize,
+        split_threshold: i32,
+        left_weight: i32,
+        right_weight: i32,
+    ) -> Result<Self> {
+        if split_threshold < 2 {
+            bail!(
+                "the split threshold must be at least 2, not {}",
+                split_threshold
+            );
+        }
+
+        Ok(Self {
+            explode_depth,
+            split_threshold,
+            left_weight,
+            right_weight,
+        })
+    }
+}
+
+impl Default for SnailRules {
+    fn default() -> Self {
+        Self {
+            explode_depth: 4,
+            split_threshold: 10,
+            left_weight: 3,
+            right_weight: 2,
+        }
+    }
+}
+
+/// A regular number, with how many pairs it's nested inside.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Leaf {
+    value: i32,
+    depth: usize,
+}
+
+/// A snailfish number as its regular numbers from left to right, each with
+/// its depth, which is enough to rebuild the pairs.
+///
+/// Neighbouring leaves at the same depth aren't always a pair: in
+/// `[[1,2],[3,4]]`, 2 and 3 aren't. What does hold is that, scanning from the
+/// left, the first leaf whose right neighbour is at the same depth starts a
+/// pair of two regular numbers. If it were a right half instead, the left
+/// half before it would hold such a pair further left. The same goes for the
+/// first such leaf deeper than any given depth, which is how `explode`
+/// finds its pair.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Snail {
+    leaves: Vec<Leaf>,
+}
+
+impl Snail {
+    #[cfg(test)]
+    fn values(&self) -> Vec<i32> {
+        self.leaves.iter().map(|leaf| leaf.value).collect()
+    }
+
+    /// Fails if the magnitude, or that of any pair inside, overflows an `i32`.
+    pub fn magnitude(&self, rules: &SnailRules) -> Result<i32> {
+        // fold each pair as soon as both halves are on the stack
+        let mut stack: Vec<Leaf> = vec![];
+        for &leaf in &self.leaves {
+            let mut leaf = leaf;
+            while let Some(&top) = stack.last() {
+                if top.depth != leaf.depth || leaf.depth == 0 {
+                    break;
+                }
+                stack.pop();
+                let value = rules
+                    .left_weight
+                    .checked_mul(top.value)
+                    .zip(rules.right_weight.checked_mul(leaf.value))
+                    .and_then(|(left, right)| left.checked_add(right))
+                    .context("magnitude overflows an i32")?;
+                leaf = Leaf {
+                    value,
+                    depth: leaf.depth - 1,
+                };
+            }
+            stack.push(leaf);
+        }
+
+        Ok(stack.first().map_or(0, |leaf| leaf.value))
+    }
+
+    /// Writes the number starting at leaf `*next`, which is `depth` pairs deep.
+    fn write(&self, f: &mut fmt::Formatter, next: &mut usize, depth: usize) -> fmt::Result {
+        let leaf = self.leaves[*next];
+        if leaf.depth == depth {
+            *next += 1;
+            return write!(f, "{}", leaf.value);
+        }
+
+        write!(f, "[")?;
+        self.write(f, next, depth + 1)?;
+        write!(f, ",")?;
+        self.write(f, next, depth + 1)?;
+        write!(f, "]")
+    }
+}
+
+impl fmt::Display for Snail {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.leaves.is_empty() {
+            return Ok(());
+        }
+        self.write(f, &mut 0, 0)
+    }
+}
+
+impl fmt::Debug for Snail {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Snail({})", self)
+    }
+}
+
+#[test]
+fn display_round_trips() {
+    for input in [
+        "[1,2]",
+        "[[1,2],3]",
+        "[9,[8,7]]",
+        "[[1,9],[8,5]]",
+        "[[[[1,2],[3,4]],[[5,6],[7,8]]],9]",
+        "[[[9,[3,8]],[[0,9],6]],[[[3,7],[4,9]],3]]",
+        "[[[[1,3],[5,3]],[[1,3],[8,7]]],[[[4,9],[6,9]],[[8,2],[7,3]]]]",
+        "[[[[[9,8],1],2],3],4]",
+        "[[10,[11,0]],12]",
+    ] {
+        let snail = parse_line(input);
+        assert_eq!(snail.to_string(), input);
+        assert_eq!(parse_line(&snail.to_string()), snail);
+    }
+}
+
+#[test]
+fn magnitude_works() {
+    let input = "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]";
+    let snail = parse_line(input);
+    let result = snail.magnitude(&SnailRules::default()).unwrap();
+    assert_eq!(result, 3488);
+}
+
+/// One action taken while reducing a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    /// The pair `[left,right]`, whose left number was leaf `index`, exploded.
+    Explode { index: usize, left: i32, right: i32 },
+    /// Leaf `index`, which was `value`, split into a pair.
+    Split { index: usize, value: i32 },
+}
+
+/// A reduction step and the number it left behind.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    pub step: Step,
+    pub result: Snail,
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.step {
+            Step::Explode { index, left, right } => {
+                write!(f, "explode [{},{}] at leaf {}", left, right, index)?
+            }
+            Step::Split { index, value } => write!(f, "split {} at leaf {}", value, index)?,
+        }
+        write!(f, ": {}", self.result)
+    }
+}
+
+fn join(left: Snail, right: Snail) -> Snail {
+    let mut leaves = left.leaves;
+    leaves.extend(right.leaves);
+    for leaf in &mut leaves {
+        leaf.depth += 1;
+    }
+
+    Snail { leaves }
+}
+
+/// Adds two numbers and reduces the sum, failing if a regular number in it
+/// overflows an `i32`.
+pub fn add(left: Snail, right: Snail, rules: &SnailRules) -> Result<Snail> {
+    let mut snail = join(left, right);
+    reduce(&mut snail, rules, |_, _| {})?;
+
+    Ok(snail)
+}
+
+/// Adds two numbers like [`add`], also returning every step of the reduction.
+pub fn add_traced(left: Snail, right: Snail, rules: &SnailRules) -> Result<(Snail, Vec<Event>)> {
+    let mut snail = join(left, right);
+    let mut events = vec![];
+    reduce(&mut snail, rules, |step, result| {
+        events.push(Event {
+            step,
+            result: result.clone(),
+        })
+    })?;
+
+    Ok((snail, events))
+}
+
+/// Reduces `snail`, passing each step to `trace` along with the number it left.
+pub fn reduce(
+    snail: &mut Snail,
+    rules: &SnailRules,
+    mut trace: impl FnMut(Step, &Snail),
+) -> Result<()> {
+    while let Some(step) = explode(snail, rules)?.or_else(|| split(snail, rules)) {
+        trace(step, snail);
+    }
+    Ok(())
+}
+
+#[test]
+fn add_works() {
+    let snail1 = parse_line("[1,2]");
+    let snail2 = parse_line("[3,4]");
+    let combined = add(snail1, snail2, &SnailRules::default()).unwrap();
+    assert_eq!(combined.values(), vec![1, 2, 3, 4])
+}
+
+fn explode(snail: &mut Snail, rules: &SnailRules) -> Result<Option<Step>> {
+    let leaves = &mut snail.leaves;
+    // only the first match is sure to be a pair, see `Snail`
+    let Some(index) = (0..leaves.len().saturating_sub(1))
+        .find(|&i| leaves[i].depth > rules.explode_depth && leaves[i + 1].depth == leaves[i].depth)
+    else {
+        return Ok(None);
+    };
+
+    let (left, right) = (leaves[index], leaves[index + 1]);
+    let overflow = || {
+        format!(
+            "exploding [{},{}] overflows an i32",
+            left.value, right.value
+        )
+    };
+    if let Some(i) = index.checked_sub(1) {
+        leaves[i].value = leaves[i]
+            .value
+            .checked_add(left.value)
+            .with_context(overflow)?;
+    }
+    if let Some(leaf) = leaves.get_mut(index + 2) {
+        leaf.value = leaf.value.checked_add(right.value).with_context(overflow)?;
+    }
+
+    leaves[index] = Leaf {
+        value: 0,
+        depth: left.depth - 1,
+    };
+    leaves.remove(index + 1);
+
+    Ok(Some(Step::Explode {
+        index,
+        left: left.value,
+        right: right.value,
+    }))
+}
+
+#[test]
+fn exploding_works() {
+    let mut snail = parse_line("[[[[[1,2],3],4],5],6]");
+    assert!(explode(&mut snail, &SnailRules::default())
+        .unwrap()
+        .is_some());
+    assert_eq!(snail.values(), vec![0, 5, 4, 5, 6])
+}
+
+fn split(snail: &mut Snail, rules: &SnailRules) -> Option<Step> {
+    let index = snail
+        .leaves
+        .iter()
+        .position(|leaf| leaf.value >= rules.split_threshold)?;
+    let Leaf { value, depth } = snail.leaves[index];
+
+    snail.leaves[index] = Leaf {
+        value: value / 2,
+        depth: depth + 1,
+    };
+    snail.leaves.insert(
+        index + 1,
+        Leaf {
+            // rounded up, without overflowing on i32::MAX
+            value: value - value / 2,
+            depth: depth + 1,
+        },
+    );
+
+    Some(Step::Split { index, value })
+}
+
+#[test]
+fn splitting_works() {
+    let mut snail = parse_line("[10,5]");
+    assert!(split(&mut snail, &SnailRules::default()).is_some());
+    assert_eq!(snail.values(), vec![5, 5, 5])
+}
+
+/// Whether parsing accepts numbers that still need reducing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Reject pairs nested deep enough to explode and numbers big enough to split.
+    Strict,
+    /// Accept pairs nested at any depth and numbers up to 99999, leaving
+    /// them for reduction.
+    Lenient,
+}
+
+/// The biggest regular number [`Mode::Lenient`] accepts. Reducing a number
+/// takes steps in proportion to its values, so much bigger ones would take
+/// minutes to add.
+const MAX_LENIENT_VALUE: i32 = 99_999;
+
+struct Parser<'a> {
+    src: &'a str,
+    rest: &'a str,
+    mode: Mode,
+    rules: SnailRules,
+    leaves: Vec<Leaf>,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, at: &str, message: impl fmt::Display) -> anyhow::Error {
+        ParseError::at(self.src, at, message).into()
+    }
+
+    /// What's next, for error messages.
+    fn found(&self) -> String {
+        match self.rest.chars().next() {
+            Some(c) => format!("{:?}", c),
+            None => "the end".to_owned(),
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<()> {
+        match self.rest.strip_prefix(c) {
+            Some(rest) => {
+                self.rest = rest;
+                Ok(())
+            }
+            None => Err(self.error(
+                self.rest,
+                format!("expected {:?}, found {}", c, self.found()),
+            )),
+        }
+    }
+
+    /// A pair whose halves are `depth + 1` pairs deep.
+    fn pair(&mut self, depth: usize) -> Result<()> {
+        if self.mode == Mode::Strict && depth >= self.rules.explode_depth {
+            return Err(self.error(
+                self.rest,
+                format!(
+                    "pair is nested inside {} others and should have exploded",
+                    depth
+                ),
+            ));
+        }
+        self.expect('[')?;
+        self.element(depth + 1)?;
+        self.expect(',')?;
+        self.element(depth + 1)?;
+        self.expect(']')
+    }
+
+    fn element(&mut self, depth: usize) -> Result<()> {
+        if self.rest.starts_with('[') {
+            return self.pair(depth);
+        }
+
+        let len = self
+            .rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(self.rest.len());
+        let (digits, rest) = self.rest.split_at(len);
+        if digits.is_empty() {
+            return Err(self.error(
+                self.rest,
+                format!("expected '[' or a number, found {}", self.found()),
+            ));
+        }
+
+        let value: i32 = digits
+            .parse()
+            .map_err(|_| self.error(digits, format!("{} is too big", digits)))?;
+        match self.mode {
+            Mode::Strict if value >= self.rules.split_threshold => {
+                return Err(self.error(digits, format!("{} should have split", value)));
+            }
+            Mode::Lenient if value > MAX_LENIENT_VALUE => {
+                return Err(self.error(
+                    digits,
+                    format!(
+                        "{} is too big to reduce, the most is {}",
+                        value, MAX_LENIENT_VALUE
+                    ),
+                ));
+            }
+            _ => {}
+        }
+        self.leaves.push(Leaf { value, depth });
+        self.rest = rest;
+
+        Ok(())
+    }
+}
+
+impl Snail {
+    /// Parses a snailfish number, which must be a pair and nothing more.
+    pub fn parse(src: &str, mode: Mode) -> Result<Self> {
+        Self::parse_with(src, mode, &SnailRules::default())
+    }
+
+    /// Parses a snailfish number, where [`Mode::Strict`] rejects numbers that
+    /// need reducing under `rules`.
+    pub fn parse_with(src: &str, mode: Mode, rules: &SnailRules) -> Result<Self> {
+        let mut parser = Parser {
+            src,
+            rest: src,
+            mode,
+            rules: *rules,
+            leaves: vec![],
+        };
+        parser.pair(0)?;
+        if !parser.rest.is_empty() {
+            return Err(parser.error(
+                parser.rest,
+                format!("expected the end, found {}", parser.found()),
+            ));
+        }
+
+        Ok(Snail {
+            leaves: parser.leaves,
+        })
+    }
+}
+
+impl FromStr for Snail {
+    type Err = anyhow::Error;
+
+    /// Parses a snailfish number in [`Mode::Lenient`].
+    fn from_str(src: &str) -> Result<Self> {
+        Snail::parse(src, Mode::Lenient)
+    }
+}
+
+/// Parses one snailfish number per line.
+pub fn parse_input(input: &str, mode: Mode) -> Result<Vec<Snail>> {
+    parse_lines(input.trim_end(), |line| Snail::parse(line, mode))
+}
+
+#[test]
+fn reduction_steps_match_the_puzzle() {
+    let rules = SnailRules::default();
+    let mut snail = add(
+        parse_line("[[[[4,3],4],4],[7,[[8,4],9]]]"),
+        parse_line("[1,1]"),
+        &rules,
+    )
+    .unwrap();
+    assert_eq!(snail.to_string(), "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]");
+
+    snail = parse_line("[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]");
+    let explode_once: fn(&mut Snail, &SnailRules) -> Option<Step> =
+        |snail, rules| explode(snail, rules).unwrap();
+    let steps = [
+        (explode_once, "[[[[0,7],4],[7,[[8,4],9]]],[1,1]]"),
+        (explode_once, "[[[[0,7],4],[15,[0,13]]],[1,1]]"),
+        (split, "[[[[0,7],4],[[7,8],[0,13]]],[1,1]]"),
+        (split, "[[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]"),
+        (explode_once, "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]"),
+    ];
+    for (step, expected) in steps {
+        assert!(step(&mut snail, &rules).is_some());
+        assert_eq!(snail.to_string(), expected);
+    }
+    assert!(explode(&mut snail, &rules).unwrap().is_none());
+    assert!(split(&mut snail, &rules).is_none());
+}
+
+#[test]
+fn reduction_is_traced() {
+    let rules = SnailRules::default();
+    let (snail, events) = add_traced(
+        parse_line("[[[[4,3],4],4],[7,[[8,4],9]]]"),
+        parse_line("[1,1]"),
+        &rules,
+    )
+    .unwrap();
+    let events = events.iter().map(Event::to_string).collect::<Vec<_>>();
+    assert_eq!(
+        events,
+        [
+            "explode [4,3] at leaf 0: [[[[0,7],4],[7,[[8,4],9]]],[1,1]]",
+            "explode [8,4] at leaf 4: [[[[0,7],4],[15,[0,13]]],[1,1]]",
+            "split 15 at leaf 3: [[[[0,7],4],[[7,8],[0,13]]],[1,1]]",
+            "split 13 at leaf 6: [[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]",
+            "explode [6,7] at leaf 6: [[[[0,7],4],[[7,8],[6,0]]],[8,1]]",
+        ]
+    );
+    assert_eq!(snail.to_string(), "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]");
+
+    let (_, events) = add_traced(parse_line("[1,2]"), parse_line("[3,4]"), &rules).unwrap();
+    assert!(events.is_empty());
+}
+
+#[cfg(test)]
+fn parse_line(s: &str) -> Snail {
+    s.parse().unwrap()
+}
+
+fn add_all(snails: &[Snail], rules: &SnailRules) -> Result<Snail> {
+    match snails
+        .iter()
+        .cloned()
+        .try_fold(None, |sum, snail| match sum {
+            Some(sum) => add(sum, snail, rules).map(Some),
+            None => Ok(Some(snail)),
+        })? {
+        Some(snail) => Ok(snail),
+        None => bail!("there are no snailfish numbers to add"),
+    }
+}
+
+#[test]
+fn add_all_works() {
+    let input = r#"[1,1]
+[2,2]
+[3,3]
+[4,4]"#;
+    let combined = add_all(
+        &parse_input(input, Mode::Strict).unwrap(),
+        &SnailRules::default(),
+    )
+    .unwrap();
+    assert_eq!(combined.values(), vec![1, 1, 2, 2, 3, 3, 4, 4]);
+    let input = r#"[1,1]
+[2,2]
+[3,3]
+[4,4]
+[5,5]"#;
+    let combined = add_all(
+        &parse_input(input, Mode::Strict).unwrap(),
+        &SnailRules::default(),
+    )
+    .unwrap();
+    assert_eq!(combined.values(), vec![3, 0, 5, 3, 4, 4, 5, 5]);
+    assert_eq!(combined.to_string(), "[[[[3,0],[5,3]],[4,4]],[5,5]]");
+
+    let input = r#"[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]
+[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]
+[[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]
+[[[[2,4],7],[6,[0,5]]],[[[6,8],[2,8]],[[2,1],[4,5]]]]
+[7,[5,[[3,8],[1,4]]]]
+[[2,[2,2]],[8,[8,1]]]
+[2,9]
+[1,[[[9,3],9],[[9,0],[0,7]]]]
+[[[5,[7,4]],7],1]
+[[[[4,2],2],6],[8,7]]"#;
+    let combined = add_all(
+        &parse_input(input, Mode::Strict).unwrap(),
+        &SnailRules::default(),
+    )
+    .unwrap();
+    assert_eq!(
+        combined.to_string(),
+        "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]"
+    );
+}
+
+fn part1(snails: &[Snail]) -> Result<i32> {
+    let rules = SnailRules::default();
+    let snail = add_all(snails, &rules)?;
+    let result = snail.magnitude(&rules)?;
+    Ok(result)
+}
+
+#[test]
+fn part1_works() {
+    // assert_eq!(part1("[1,3]"), Ok(2));
+    assert_eq!(
+        part1(
+            &parse_input(
+                r#"[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+[[[5,[2,8]],4],[5,[[9,9],0]]]
+[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
+[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
+[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
+[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
+[[[[5,4],[7,7]],8],[[8,3],8]]
+[[9,3],[[9,9],[6,[4,9]]]]
+[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
+[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]"#,
+                Mode::Strict
+            )
+            .unwrap()
+        )
+        .unwrap(),
+        4140
+    )
+}
+
+/// The two numbers, by index, whose sum has the biggest magnitude.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BestPair {
+    pub left: usize,
+    pub right: usize,
+    pub magnitude: i32,
+}
+
+impl BestPair {
+    /// Orders pairs by magnitude, then by which comes first.
+    fn rank(&self) -> (i32, Reverse<usize>, Reverse<usize>) {
+        (self.magnitude, Reverse(self.left), Reverse(self.right))
+    }
+}
+
+/// Adds every ordered pair of different numbers, spread over as many
+/// threads as there are cores, and returns the pair with the biggest
+/// magnitude. Ties go to the pair that comes first. `None` if there are fewer
+/// than two numbers.
+pub fn best_pair(snails: &[Snail], rules: &SnailRules) -> Result<Option<BestPair>> {
+    let threads = thread::available_parallelism()
+        .map_or(1, |n| n.get())
+        .min(snails.len())
+        .max(1);
+
+    // each thread takes every `threads`th left number
+    let best_from = |first: usize| -> Result<Option<BestPair>> {
+        let mut best: Option<BestPair> = None;
+        for left in (first..snails.len()).step_by(threads) {
+            for right in (0..snails.len()).filter(|&right| right != left) {
+                let context = || format!("adding numbers {} and {}", left + 1, right + 1);
+                let sum = add(snails[left].clone(), snails[right].clone(), rules)
+                    .with_context(context)?;
+                let pair = BestPair {
+                    left,
+                    right,
+                    magnitude: sum.magnitude(rules).with_context(context)?,
+                };
+                if best.is_none_or(|best| pair.rank() > best.rank()) {
+                    best = Some(pair);
+                }
+            }
+        }
+        Ok(best)
+    };
+
+    thread::scope(|scope| {
+        let handles = (0..threads)
+            .map(|first| scope.spawn(move || best_from(first)))
+            .collect::<Vec<_>>();
+        let bests = handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect::<Result<Vec<_>>>()?;
+        Ok(bests.into_iter().flatten().max_by_key(BestPair::rank))
+    })
+}
+
+fn part2(snails: &[Snail]) -> Result<i32> {
+    let best = best_pair(snails, &SnailRules::default())?
+        .ok_or_else(|| anyhow!("there need to be at least two snailfish numbers"))?;
+    Ok(best.magnitude)
+}
+
+#[test]
+fn part2_works() {
+    let input = r#"[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+[[[5,[2,8]],4],[5,[[9,9],0]]]
+[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
+[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
+[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
+[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
+[[[[5,4],[7,7]],8],[[8,3],8]]
+[[9,3],[[9,9],[6,[4,9]]]]
+[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
+[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]"#;
+    assert_eq!(
+        part2(&parse_input(input, Mode::Strict).unwrap()).unwrap(),
+        3993
+    );
+    assert_eq!(
+        best_pair(
+            &parse_input(input, Mode::Strict).unwrap(),
+            &SnailRules::default()
+        )
+        .unwrap(),
+        Some(BestPair {
+            left: 8,
+            right: 0,
+            magnitude: 3993
+        })
+    );
+}
+
+#[test]
+fn equal_numbers_on_different_lines_are_added() {
+    let snails = parse_input("[1,1]\n[1,1]", Mode::Strict).unwrap();
+    assert_eq!(
+        best_pair(&snails, &SnailRules::default()).unwrap(),
+        Some(BestPair {
+            left: 0,
+            right: 1,
+            magnitude: 25
+        })
+    );
+
+    let snails = parse_input("[1,1]", Mode::Strict).unwrap();
+    assert_eq!(best_pair(&snails, &SnailRules::default()).unwrap(), None);
+    assert_eq!(
+        part2(&snails).unwrap_err().to_string(),
+        "there need to be at least two snailfish numbers"
+    );
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<Snail>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input, Mode::Lenient)
+    }
+
+    fn part1(input: &Self::Input) -> Result<i32> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<i32> {
+        part2(input)
+    }
+}
+
+#[test]
+fn bad_numbers_are_reported() {
+    let err = Day18::parse("[1,2]\n[[3,4],x]").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 2, column 8: expected '[' or a number, found 'x'"
+    );
+
+    let err = Day18::parse("[1,99999999999]").unwrap_err();
+    assert_eq!(err.to_string(), "line 1, column 4: 99999999999 is too big");
+
+    let err = |src: &str| src.parse::<Snail>().unwrap_err().to_string();
+    assert_eq!(
+        err("[1,2]]"),
+        "line 1, column 6: expected the end, found ']'"
+    );
+    assert_eq!(
+        err("[1,2] "),
+        "line 1, column 6: expected the end, found ' '"
+    );
+    assert_eq!(err("[1 2]"), "line 1, column 3: expected ',', found ' '");
+    assert_eq!(
+        err("[1,[2,3]"),
+        "line 1, column 9: expected ']', found the end"
+    );
+    assert_eq!(err("1"), "line 1, column 1: expected '[', found '1'");
+    assert_eq!(err(""), "line 1, column 1: expected '[', found the end");
+}
+
+#[test]
+fn unreduced_numbers_are_rejected_when_strict() {
+    let err = parse_input("[1,2]\n[[[[[9,8],1],2],3],4]", Mode::Strict).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 2, column 5: pair is nested inside 4 others and should have exploded"
+    );
+
+    let err = parse_input("[[1,2],[3,12]]", Mode::Strict).unwrap_err();
+    assert_eq!(err.to_string(), "line 1, column 11: 12 should have split");
+
+    for src in ["[[[[[9,8],1],2],3],4]", "[[1,2],[3,12]]"] {
+        assert_eq!(Snail::parse(src, Mode::Lenient).unwrap().to_string(), src);
+        assert_eq!(Day18::parse(src).unwrap()[0].to_string(), src);
+    }
+}
+
+#[test]
+fn nothing_to_add_is_an_error() {
+    assert_eq!(
+        add_all(&[], &SnailRules::default())
+            .unwrap_err()
+            .to_string(),
+        "there are no snailfish numbers to add"
+    );
+}
+
+#[test]
+fn rules_can_be_changed() {
+    let rules = SnailRules::new(2, 5, 1, 1).unwrap();
+    let (snail, events) = add_traced(parse_line("[[3,4],1]"), parse_line("[2,6]"), &rules).unwrap();
+    let events = events.iter().map(Event::to_string).collect::<Vec<_>>();
+    assert_eq!(
+        events,
+        [
+            "explode [3,4] at leaf 0: [[0,5],[2,6]]",
+            "split 5 at leaf 1: [[0,[2,3]],[2,6]]",
+            "explode [2,3] at leaf 1: [[2,0],[5,6]]",
+            "split 5 at leaf 2: [[2,0],[[2,3],6]]",
+            "explode [2,3] at leaf 2: [[2,2],[0,9]]",
+            "split 9 at leaf 3: [[2,2],[0,[4,5]]]",
+            "explode [4,5] at leaf 3: [[2,2],[4,0]]",
+        ]
+    );
+    assert_eq!(snail.magnitude(&rules).unwrap(), 8);
+
+    let err = Snail::parse_with("[[1,[2,3]],4]", Mode::Strict, &rules).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 1, column 5: pair is nested inside 2 others and should have exploded"
+    );
+}
+
+#[test]
+fn bad_rules_are_rejected() {
+    for threshold in [1, 0, -3] {
+        assert_eq!(
+            SnailRules::new(4, threshold, 3, 2).unwrap_err().to_string(),
+            format!("the split threshold must be at least 2, not {}", threshold)
+        );
+    }
+
+    let rules = SnailRules::new(4, 10, i32::MAX, 2).unwrap();
+    let err = parse_line("[[1,1],1]").magnitude(&rules).unwrap_err();
+    assert_eq!(err.to_string(), "magnitude overflows an i32");
+
+    let err = best_pair(&[parse_line("[1,1]"), parse_line("[2,2]")], &rules).unwrap_err();
+    assert_eq!(
+        format!("{:#}", err),
+        "adding numbers 1 and 2: magnitude overflows an i32"
+    );
+}
+
+#[test]
+fn overflowing_numbers_are_reported() {
+    let rules = SnailRules::default();
+    // too big to parse, so set straight into the leaves
+    let mut big = parse_line("[[[[[1,1],0],0],0],0]");
+    big.leaves[2].value = i32::MAX;
+    let err = add(big, parse_line("[0,0]"), &rules).unwrap_err();
+    assert_eq!(err.to_string(), "exploding [1,1] overflows an i32");
+
+    let mut snail = parse_line("[0,1]");
+    snail.leaves[0].value = i32::MAX;
+    assert_eq!(
+        split(&mut snail, &rules),
+        Some(Step::Split {
+            index: 0,
+            value: i32::MAX
+        })
+    );
+    assert_eq!(snail.to_string(), "[[1073741823,1073741824],1]");
+
+    let snail = add(parse_line("[99999,1]"), parse_line("[1,1]"), &rules).unwrap();
+    assert_eq!(snail.magnitude(&rules).unwrap(), 4118);
+
+    let err = "[2147483647,1]".parse::<Snail>().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "line 1, column 2: 2147483647 is too big to reduce, the most is 99999"
+    );
+}
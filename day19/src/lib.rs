@@ -0,0 +1,451 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{bail, Result};
+use aoc_common::{
+    parse::{parse_lines, within},
+    ParseError, Solution,
+};
+
+type Point = [i32; 3];
+type Rotation = [[i32; 3]; 3];
+
+/// How many beacons two scanners must both see before they count as overlapping.
+const OVERLAP: usize = 12;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scanner {
+    beacons: Vec<Point>,
+}
+
+fn parse_point(line: &str) -> Result<Point> {
+    let mut coords = line.split(',');
+    let mut point = [0; 3];
+
+    for (axis, coord) in point.iter_mut().enumerate() {
+        let part = coords.next().ok_or_else(|| {
+            ParseError::new(
+                1,
+                line.chars().count() + 1,
+                format!("expected 3 coordinates, found {}", axis),
+            )
+        })?;
+        *coord = part
+            .parse()
+            .map_err(|_| ParseError::at(line, part, format!("{:?} is not a coordinate", part)))?;
+    }
+    if let Some(extra) = coords.next() {
+        return Err(ParseError::at(line, extra, "expected 3 coordinates").into());
+    }
+
+    Ok(point)
+}
+
+fn parse_input(input: &str) -> Result<Vec<Scanner>> {
+    let mut scanners = vec![];
+
+    for block in input.trim_end().split("\n\n") {
+        let (header, beacons) = block
+            .split_once('\n')
+            .unwrap_or((block, &block[block.len()..]));
+        let number = header
+            .strip_prefix("--- scanner ")
+            .and_then(|header| header.strip_suffix(" ---"))
+            .ok_or_else(|| ParseError::at(input, header, "expected \"--- scanner N ---\""))?;
+        if number != scanners.len().to_string() {
+            return Err(ParseError::at(
+                input,
+                number,
+                format!("expected scanner {}, found {:?}", scanners.len(), number),
+            )
+            .into());
+        }
+
+        let beacons = within(input, beacons, |beacons| parse_lines(beacons, parse_point))?;
+        if beacons.is_empty() {
+            return Err(ParseError::at(
+                input,
+                header,
+                format!("scanner {} sees no beacons", number),
+            )
+            .into());
+        }
+        scanners.push(Scanner { beacons });
+    }
+
+    Ok(scanners)
+}
+
+fn determinant(m: &Rotation) -> i32 {
+    m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+        - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+        + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+}
+
+/// The 24 ways a scanner can be facing: every axis permutation and sign
+/// flip that doesn't mirror the scanner.
+fn rotations() -> Vec<Rotation> {
+    let permutations = [
+        [0, 1, 2],
+        [0, 2, 1],
+        [1, 0, 2],
+        [1, 2, 0],
+        [2, 0, 1],
+        [2, 1, 0],
+    ];
+    let mut rotations = vec![];
+
+    for permutation in permutations {
+        for signs in 0..8 {
+            let mut m = [[0; 3]; 3];
+            for (row, col) in permutation.into_iter().enumerate() {
+                m[row][col] = if signs >> row & 1 == 1 { -1 } else { 1 };
+            }
+            if determinant(&m) == 1 {
+                rotations.push(m);
+            }
+        }
+    }
+
+    rotations
+}
+
+#[test]
+fn rotations_are_distinct() {
+    let rotations = rotations();
+    let points = rotations
+        .iter()
+        .map(|m| rotate(m, &[1, 2, 3]))
+        .collect::<HashSet<_>>();
+    assert_eq!(rotations.len(), 24);
+    assert_eq!(points.len(), 24);
+}
+
+fn rotate(m: &Rotation, p: &Point) -> Point {
+    [0, 1, 2].map(|row| (0..3).map(|col| m[row][col] * p[col]).sum())
+}
+
+fn add(a: &Point, b: &Point) -> Point {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn sub(a: &Point, b: &Point) -> Point {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn manhattan(a: &Point, b: &Point) -> i32 {
+    sub(a, b).iter().map(|d| d.abs()).sum()
+}
+
+/// Finds the rotation and position at which at least [`OVERLAP`] of
+/// `beacons` land on `known`, returning the scanner's position and its
+/// beacons in `known`'s coordinates.
+fn locate(
+    known: &[Point],
+    beacons: &[Point],
+    rotations: &[Rotation],
+) -> Option<(Point, Vec<Point>)> {
+    rotations.iter().find_map(|rotation| {
+        let rotated = beacons
+            .iter()
+            .map(|beacon| rotate(rotation, beacon))
+            .collect::<Vec<_>>();
+        let mut offsets = HashMap::new();
+
+        for k in known {
+            for r in &rotated {
+                let offset = sub(k, r);
+                let count = offsets.entry(offset).or_insert(0);
+                *count += 1;
+
+                if *count >= OVERLAP {
+                    let beacons = rotated.iter().map(|r| add(r, &offset)).collect();
+                    return Some((offset, beacons));
+                }
+            }
+        }
+
+        None
+    })
+}
+
+/// Every scanner's position and every beacon, relative to scanner 0.
+struct Map {
+    scanners: Vec<Point>,
+    beacons: HashSet<Point>,
+}
+
+fn build_map(scanners: &[Scanner]) -> Result<Map> {
+    let rotations = rotations();
+    let mut placed: Vec<Option<(Point, Vec<Point>)>> = vec![None; scanners.len()];
+    let mut queue = vec![];
+
+    if let Some(first) = scanners.first() {
+        placed[0] = Some(([0; 3], first.beacons.clone()));
+        queue.push(0);
+    }
+
+    // each scanner is only compared against the others once, when it's placed
+    while let Some(i) = queue.pop() {
+        let known = placed[i].as_ref().map(|(_, beacons)| beacons.clone());
+        let known = known.unwrap_or_default();
+
+        for (j, scanner) in scanners.iter().enumerate() {
+            if placed[j].is_none() {
+                placed[j] = locate(&known, &scanner.beacons, &rotations);
+                if placed[j].is_some() {
+                    queue.push(j);
+                }
+            }
+        }
+    }
+
+    let mut map = Map {
+        scanners: vec![],
+        beacons: HashSet::new(),
+    };
+    for (i, placed) in placed.into_iter().enumerate() {
+        let Some((position, beacons)) = placed else {
+            bail!(
+                "scanner {} doesn't share {} beacons with any scanner linked to scanner 0",
+                i,
+                OVERLAP
+            );
+        };
+        map.scanners.push(position);
+        map.beacons.extend(beacons);
+    }
+
+    Ok(map)
+}
+
+fn part1(scanners: &[Scanner]) -> Result<usize> {
+    Ok(build_map(scanners)?.beacons.len())
+}
+
+fn part2(scanners: &[Scanner]) -> Result<i32> {
+    let map = build_map(scanners)?;
+
+    Ok(map
+        .scanners
+        .iter()
+        .flat_map(|a| map.scanners.iter().map(move |b| manhattan(a, b)))
+        .max()
+        .unwrap_or(0))
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Vec<Scanner>;
+    type Part1 = usize;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(scanners: &Self::Input) -> Result<usize> {
+        part1(scanners)
+    }
+
+    fn part2(scanners: &Self::Input) -> Result<i32> {
+        part2(scanners)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = r#"--- scanner 0 ---
+404,-588,-901
+528,-643,409
+-838,591,734
+390,-675,-793
+-537,-823,-458
+-485,-357,347
+-345,-311,381
+-661,-816,-575
+-876,649,763
+-618,-824,-621
+553,345,-567
+474,580,667
+-447,-329,318
+-584,868,-557
+544,-627,-890
+564,392,-477
+455,729,728
+-892,524,684
+-689,845,-530
+423,-701,434
+7,-33,-71
+630,319,-379
+443,580,662
+-789,900,-551
+459,-707,401
+
+--- scanner 1 ---
+686,422,578
+605,423,415
+515,917,-361
+-336,658,858
+95,138,22
+-476,619,847
+-340,-569,-846
+567,-361,727
+-460,603,-452
+669,-402,600
+729,430,532
+-500,-761,534
+-322,571,750
+-466,-666,-811
+-429,-592,574
+-355,545,-477
+703,-491,-529
+-328,-685,520
+413,935,-424
+-391,539,-444
+586,-435,557
+-364,-763,-893
+807,-499,-711
+755,-354,-619
+553,889,-390
+
+--- scanner 2 ---
+649,640,665
+682,-795,504
+-784,533,-524
+-644,584,-595
+-588,-843,648
+-30,6,44
+-674,560,763
+500,723,-460
+609,671,-379
+-555,-800,653
+-675,-892,-343
+697,-426,-610
+578,704,681
+493,664,-388
+-671,-858,530
+-667,343,800
+571,-461,-707
+-138,-166,112
+-889,563,-600
+646,-828,498
+640,759,510
+-630,509,768
+-681,-892,-333
+673,-379,-804
+-742,-814,-386
+577,-820,562
+
+--- scanner 3 ---
+-589,542,597
+605,-692,669
+-500,565,-823
+-660,373,557
+-458,-679,-417
+-488,449,543
+-626,468,-788
+338,-750,-386
+528,-832,-391
+562,-778,733
+-938,-730,414
+543,643,-506
+-524,371,-870
+407,773,750
+-104,29,83
+378,-903,-323
+-778,-728,485
+426,699,580
+-438,-605,-362
+-469,-447,-387
+509,732,623
+647,635,-688
+-868,-804,481
+614,-800,639
+595,780,-596
+
+--- scanner 4 ---
+727,592,562
+-293,-554,779
+441,611,-461
+-714,465,-776
+-743,427,-804
+-660,-479,-426
+832,-632,460
+927,-485,-438
+408,393,-506
+466,436,-512
+110,16,151
+-258,-428,682
+-393,719,612
+-211,-452,876
+808,-476,-593
+-575,615,604
+-485,667,467
+-680,325,-822
+-627,-443,-432
+872,-547,-609
+833,512,582
+807,604,487
+839,-516,451
+891,-625,532
+-652,-548,-490
+30,-46,-14"#;
+
+    #[test]
+    fn scanners_are_placed() {
+        let map = build_map(&parse_input(EXAMPLE).unwrap()).unwrap();
+        assert_eq!(
+            map.scanners,
+            vec![
+                [0, 0, 0],
+                [68, -1246, -43],
+                [1105, -1205, 1229],
+                [-92, -2380, -20],
+                [-20, -1133, 1061],
+            ]
+        );
+    }
+
+    #[test]
+    fn part1_works() {
+        assert_eq!(part1(&parse_input(EXAMPLE).unwrap()).unwrap(), 79);
+    }
+
+    #[test]
+    fn part2_works() {
+        assert_eq!(part2(&parse_input(EXAMPLE).unwrap()).unwrap(), 3621);
+    }
+
+    #[test]
+    fn bad_reports_are_rejected() {
+        let err = |input| parse_input(input).unwrap_err().to_string();
+        assert_eq!(
+            err("--- scanner 0 ---\n1,2,3\n4,x,6"),
+            "line 3, column 3: \"x\" is not a coordinate"
+        );
+        assert_eq!(
+            err("--- scanner 0 ---\n1,2\n"),
+            "line 2, column 4: expected 3 coordinates, found 2"
+        );
+        assert_eq!(
+            err("--- scanner 0 ---\n1,2,3\n\n--- scanner 2 ---\n1,2,3"),
+            "line 4, column 13: expected scanner 1, found \"2\""
+        );
+        assert_eq!(
+            err("--- scanner 0 ---"),
+            "line 1, column 1: scanner 0 sees no beacons"
+        );
+    }
+
+    #[test]
+    fn lone_scanners_are_reported() {
+        let scanners = parse_input("--- scanner 0 ---\n1,2,3\n\n--- scanner 1 ---\n1,2,3").unwrap();
+        assert_eq!(
+            part1(&scanners).unwrap_err().to_string(),
+            "scanner 1 doesn't share 12 beacons with any scanner linked to scanner 0"
+        );
+    }
+}
@@ -0,0 +1,3 @@
+fn main() -> anyhow::Result<()> {
+    aoc_common::solve::<day19::Day19>()
+}
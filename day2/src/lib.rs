@@ -0,0 +1,131 @@
+use std::str::FromStr;
+
+use anyhow::{Error, Result};
+use aoc_common::{parse::parse_lines, ParseError, Solution};
+
+#[derive(Debug)]
+pub enum Move {
+    Forward(isize),
+    Down(isize),
+    Up(isize),
+}
+
+impl FromStr for Move {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        use Move::*;
+
+        let (direction, magnitude) = input
+            .split_once(' ')
+            .ok_or_else(|| ParseError::at(input, input, "expected `<direction> <magnitude>`"))?;
+        let magnitude = magnitude.parse::<isize>().map_err(|_| {
+            ParseError::at(
+                input,
+                magnitude,
+                format!("{:?} is not a magnitude", magnitude),
+            )
+        })?;
+        Ok(match direction {
+            "forward" => Forward(magnitude),
+            "down" => Down(magnitude),
+            "up" => Up(magnitude),
+            _ => {
+                return Err(ParseError::at(
+                    input,
+                    direction,
+                    format!("unknown direction {:?}", direction),
+                )
+                .into())
+            }
+        })
+    }
+}
+
+struct Sub {
+    aim: isize,
+    x: isize,
+    y: isize,
+}
+
+impl Sub {
+    fn apply(&mut self, m: &Move) {
+        match m {
+            Move::Forward(mag) => {
+                self.x += mag;
+                self.y += self.aim * mag;
+            }
+            Move::Down(mag) => self.aim += mag,
+            Move::Up(mag) => self.aim -= mag,
+        }
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Move>;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_lines(input, str::parse)
+    }
+
+    fn part1(moves: &Self::Input) -> Result<isize> {
+        let mut coord = (0isize, 0isize);
+
+        for m in moves {
+            m.apply(&mut coord)
+        }
+
+        Ok(coord.0 * coord.1)
+    }
+
+    fn part2(moves: &Self::Input) -> Result<isize> {
+        let mut sub = Sub { aim: 0, x: 0, y: 0 };
+        for m in moves {
+            sub.apply(m);
+        }
+
+        Ok(sub.x * sub.y)
+    }
+}
+
+impl Move {
+    pub(crate) fn apply(&self, coord: &mut (isize, isize)) {
+        use Move::*;
+
+        match self {
+            Forward(mag) => coord.0 += mag,
+            Down(mag) => coord.1 += mag,
+            Up(mag) => coord.1 -= mag,
+        };
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bad_moves_are_reported() {
+        let err = Day2::parse("forward 5\nback 3").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: unknown direction \"back\""
+        );
+
+        let err = Day2::parse("down x").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 6: \"x\" is not a magnitude"
+        );
+
+        let err = Day2::parse("up5").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: expected `<direction> <magnitude>`"
+        );
+    }
+}
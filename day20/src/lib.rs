@@ -0,0 +1,256 @@
+use anyhow::{bail, Error, Result};
+use aoc_common::{bits::fold_bit, grid::WINDOW, parse::within, Grid, ParseError, Solution};
+use std::{collections::HashSet, fmt::Display, str::FromStr};
+
+struct Mask {
+    inner: Vec<bool>,
+    zeroth_border: bool,
+}
+
+impl Mask {
+    fn get(&self, num: usize) -> &bool {
+        &self.inner[num]
+    }
+
+    fn default(&self, num: usize) -> &bool {
+        if num.is_multiple_of(2) {
+            &false
+        } else {
+            &self.zeroth_border
+        }
+    }
+}
+
+impl FromStr for Mask {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let inner = s
+            .chars()
+            .enumerate()
+            .map(|(i, c)| match c {
+                '#' => Ok(true),
+                '.' => Ok(false),
+                _ => Err(ParseError::new(1, i + 1, format!("{:?} is not a pixel", c))),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if inner.len() != 512 {
+            return Err(ParseError::new(
+                1,
+                inner.len().min(512) + 1,
+                format!("expected 512 pixels, found {}", inner.len()),
+            )
+            .into());
+        }
+        let zeroth_border = inner[0]; // when all empty;
+
+        Ok(Self {
+            inner,
+            zeroth_border,
+        })
+    }
+}
+
+struct Image {
+    inner: HashSet<(isize, isize)>,
+    ranges: (std::ops::Range<isize>, std::ops::Range<isize>),
+}
+
+impl Image {
+    fn enhance(&mut self, mask: &Mask, iter_num: usize) -> Result<()> {
+        let x_range = (self.ranges.0.start - 1)..(self.ranges.0.end + 1);
+        let y_range = (self.ranges.1.start - 1)..(self.ranges.1.end + 1);
+
+        let mask_default = mask.default(iter_num);
+
+        let inner = &self.inner;
+        let new = x_range
+            .clone()
+            .flat_map(|x| {
+                let x_r = self.ranges.0.clone();
+                let y_r = self.ranges.1.clone();
+
+                y_range.clone().filter_map(move |y| {
+                    let num = all_cells(&(x, y))
+                        .map(|cell| {
+                            if x_r.contains(&cell.0) && y_r.contains(&cell.1) {
+                                inner.contains(&cell)
+                            } else {
+                                *mask_default
+                            }
+                        })
+                        .fold(0, fold_bit);
+
+                    mask.get(num).then_some((x, y))
+                })
+            })
+            .collect::<HashSet<(isize, isize)>>();
+
+        self.ranges = (x_range, y_range);
+        self.inner = new;
+        Ok(())
+    }
+
+    fn count(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl Display for Image {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (ref x_range, ref y_range) = &self.ranges;
+
+        let result = (y_range.clone())
+            .map(|y: isize| {
+                x_range
+                    .clone()
+                    .map(|x: isize| match self.inner.get(&(x, y)) {
+                        Some(_) => '█',
+                        _ => ' ',
+                    })
+                    .chain(std::iter::once('\n'))
+                    .collect::<String>()
+            })
+            .collect::<String>();
+
+        write!(f, "{}", result)
+    }
+}
+
+fn all_cells(coord: &(isize, isize)) -> impl Iterator<Item = (isize, isize)> + '_ {
+    WINDOW
+        .iter()
+        .map(|(o_x, o_y)| (coord.0 + o_x, coord.1 + o_y))
+}
+
+impl FromStr for Image {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = Grid::parse_with(s, |c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => bail!("not a pixel"),
+        })?;
+        let y_range = 0..grid.height() as isize;
+        let x_range = 0..grid.width() as isize;
+
+        let inner = grid
+            .iter()
+            .filter(|(_, lit)| **lit)
+            .map(|((x, y), _)| (x as isize, y as isize))
+            .collect::<HashSet<_, _>>();
+
+        Ok(Self {
+            inner,
+            ranges: ((x_range), (y_range)),
+        })
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Mask, Image)> {
+    let (mask, map) = input.split_once("\n\n").ok_or_else(|| {
+        ParseError::at(input, input, "expected a mask, a blank line, then an image")
+    })?;
+    let mask = within(input, mask, str::parse)?;
+    let map = within(input, map, str::parse)?;
+
+    Ok((mask, map))
+}
+
+fn part1(input: &str) -> Result<usize> {
+    let (mask, mut map) = parse_input(input)?;
+    for i in 0..2 {
+        map.enhance(&mask, i)?;
+    }
+
+    Ok(map.count())
+}
+
+fn part2(input: &str) -> Result<usize> {
+    let (mask, mut map) = parse_input(input)?;
+    for i in 0..50 {
+        map.enhance(&mask, i)?;
+    }
+
+    Ok(map.count())
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)?;
+        Ok(input.to_owned())
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn part1_works() {
+        let input = r#"..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#
+
+#..#.
+#....
+##..#
+..#..
+..###"#;
+        assert_eq!(part1(input).unwrap(), 35);
+    }
+
+    #[test]
+    fn part2_works() {
+        let input = r#"..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#
+
+#..#.
+#....
+##..#
+..#..
+..###"#;
+        assert_eq!(part2(input).unwrap(), 3351)
+    }
+
+    #[test]
+    fn border_works() {
+        let input = r#"###.#...#.......#...............#...............................#...............................................................#...............................................................................................................................#...............................................................................................................................................................................................................................................................
+
+#....
+.....
+.....
+.....
+....."#;
+        assert_eq!(part1(input).unwrap(), 0)
+    }
+
+    #[test]
+    fn bad_images_are_reported() {
+        let mask = "#.".repeat(256);
+
+        let err = Day20::parse(&format!("{}\n\n#..#.\n#.o..", mask)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 3: invalid cell 'o': not a pixel"
+        );
+
+        let err = Day20::parse(&format!("{}#\n\n#..#.", mask)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 513: expected 512 pixels, found 513"
+        );
+    }
+}
@@ -0,0 +1,349 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+use anyhow::{bail, Result};
+use aoc_common::{ParseError, Solution};
+
+const HALLWAY: usize = 11;
+const ROOMS: usize = 4;
+const EMPTY: u8 = b'.';
+
+/// The rows part 2 finds folded into the middle of every room.
+const FOLDED: [[u8; ROOMS]; 2] = [*b"DCBA", *b"DBAC"];
+
+/// Which room an amphipod belongs in.
+fn room_of(amphipod: u8) -> usize {
+    (amphipod - b'A') as usize
+}
+
+fn energy(amphipod: u8) -> usize {
+    10usize.pow(room_of(amphipod) as u32)
+}
+
+/// The hallway spot right outside `room`, where nobody may stop.
+fn door(room: usize) -> usize {
+    2 + 2 * room
+}
+
+/// The hallway, then each room from its top slot down.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Burrow {
+    cells: Vec<u8>,
+    depth: usize,
+}
+
+impl Burrow {
+    fn slot(&self, room: usize, depth: usize) -> usize {
+        HALLWAY + room * self.depth + depth
+    }
+
+    fn room(&self, room: usize) -> &[u8] {
+        &self.cells[self.slot(room, 0)..self.slot(room + 1, 0)]
+    }
+
+    fn is_organised(&self) -> bool {
+        (0..ROOMS).all(|room| {
+            self.room(room)
+                .iter()
+                .all(|&amphipod| amphipod != EMPTY && room_of(amphipod) == room)
+        })
+    }
+
+    /// Whether the hallway between `from` and `to` is empty, not counting `from`.
+    fn is_clear(&self, from: usize, to: usize) -> bool {
+        let path = if from < to {
+            from + 1..=to
+        } else {
+            to..=from - 1
+        };
+        self.cells[path].iter().all(|&cell| cell == EMPTY)
+    }
+
+    fn moved(&self, from: usize, to: usize) -> Self {
+        let mut next = self.clone();
+        next.cells.swap(from, to);
+        next
+    }
+
+    /// Every burrow one move away, with the energy the move takes.
+    fn moves(&self) -> Vec<(usize, Self)> {
+        let mut moves = vec![];
+
+        // into its own room, once only its own kind is left in there
+        for x in 0..HALLWAY {
+            let amphipod = self.cells[x];
+            if amphipod == EMPTY {
+                continue;
+            }
+            let room = room_of(amphipod);
+            let cells = self.room(room);
+            if !self.is_clear(x, door(room))
+                || cells.iter().any(|&cell| cell != EMPTY && cell != amphipod)
+            {
+                continue;
+            }
+            if let Some(depth) = cells.iter().rposition(|&cell| cell == EMPTY) {
+                let steps = x.abs_diff(door(room)) + depth + 1;
+                moves.push((
+                    steps * energy(amphipod),
+                    self.moved(x, self.slot(room, depth)),
+                ));
+            }
+        }
+
+        // out of a room into the hallway, unless everyone in it is already home
+        for room in 0..ROOMS {
+            let cells = self.room(room);
+            let Some(depth) = cells.iter().position(|&cell| cell != EMPTY) else {
+                continue;
+            };
+            if cells[depth..].iter().all(|&cell| room_of(cell) == room) {
+                continue;
+            }
+            let amphipod = cells[depth];
+
+            for x in (0..HALLWAY).filter(|x| !(0..ROOMS).any(|room| door(room) == *x)) {
+                if self.is_clear(door(room), x) {
+                    let steps = depth + 1 + x.abs_diff(door(room));
+                    moves.push((
+                        steps * energy(amphipod),
+                        self.moved(self.slot(room, depth), x),
+                    ));
+                }
+            }
+        }
+
+        moves
+    }
+
+    /// The part 2 burrow: two more rows unfolded between the first and last.
+    pub fn unfold(&self) -> Result<Self> {
+        if self.depth != 2 {
+            bail!(
+                "only a 2-deep burrow can be unfolded, this one is {} deep",
+                self.depth
+            );
+        }
+
+        let mut cells = self.cells[..HALLWAY].to_vec();
+        for room in 0..ROOMS {
+            let top = self.room(room);
+            cells.push(top[0]);
+            cells.extend(FOLDED.iter().map(|row| row[room]));
+            cells.push(top[1]);
+        }
+
+        Ok(Self { cells, depth: 4 })
+    }
+}
+
+fn parse_input(input: &str) -> Result<Burrow> {
+    let lines = input.lines().collect::<Vec<_>>();
+    let error = |line: usize, column: usize, message: &str| -> anyhow::Error {
+        ParseError::new(line + 1, column + 1, message).into()
+    };
+
+    let hallway = lines
+        .get(1)
+        .and_then(|line| line.strip_prefix('#'))
+        .and_then(|line| line.strip_suffix('#'))
+        .filter(|hallway| hallway.len() == HALLWAY)
+        .ok_or_else(|| error(1, 0, "expected an 11 spot hallway between walls"))?;
+    let mut cells = hallway.bytes().collect::<Vec<_>>();
+    for (x, cell) in cells.iter().enumerate() {
+        if *cell != EMPTY && !(b'A'..=b'D').contains(cell) {
+            return Err(error(1, x + 1, "expected an amphipod or '.'"));
+        }
+        if *cell != EMPTY && (0..ROOMS).any(|room| door(room) == x) {
+            return Err(error(1, x + 1, "amphipods never stop outside a room"));
+        }
+    }
+
+    let rows = lines
+        .iter()
+        .skip(2)
+        .take_while(|line| line.trim() != "#########")
+        .collect::<Vec<_>>();
+    if rows.is_empty() || 2 + rows.len() == lines.len() {
+        return Err(error(
+            lines.len(),
+            0,
+            "expected rooms closed off by \"#########\"",
+        ));
+    }
+
+    let mut rooms = vec![vec![]; ROOMS];
+    for (y, row) in rows.iter().enumerate() {
+        let row = row.as_bytes();
+        for (room, cells) in rooms.iter_mut().enumerate() {
+            let x = door(room) + 1;
+            match row.get(x) {
+                Some(&EMPTY) if cells.last().is_some_and(|&above| above != EMPTY) => {
+                    return Err(error(
+                        y + 2,
+                        x,
+                        "rooms fill from the bottom, not over a gap",
+                    ))
+                }
+                Some(&cell) if cell == EMPTY || (b'A'..=b'D').contains(&cell) => cells.push(cell),
+                _ => return Err(error(y + 2, x, "expected an amphipod or '.'")),
+            }
+            if row.get(x + 1) != Some(&b'#') {
+                return Err(error(y + 2, x + 1, "expected a wall"));
+            }
+        }
+    }
+
+    let depth = rows.len();
+    cells.extend(rooms.into_iter().flatten());
+    for amphipod in b'A'..=b'D' {
+        let count = cells.iter().filter(|&&cell| cell == amphipod).count();
+        if count != depth {
+            bail!(
+                "there should be {} amphipods of type {}, found {}",
+                depth,
+                amphipod as char,
+                count
+            );
+        }
+    }
+
+    Ok(Burrow { cells, depth })
+}
+
+/// The least energy it takes to get every amphipod into its own room.
+pub fn organise(burrow: &Burrow) -> Result<usize> {
+    let mut open_set = BinaryHeap::new();
+    open_set.push(Reverse((0, burrow.clone())));
+    let mut energy_map = HashMap::new();
+    energy_map.insert(burrow.clone(), 0);
+
+    while let Some(Reverse((energy, burrow))) = open_set.pop() {
+        if burrow.is_organised() {
+            return Ok(energy);
+        }
+        if energy_map.get(&burrow).is_some_and(|&best| best < energy) {
+            continue;
+        }
+
+        for (cost, next) in burrow.moves() {
+            let next_energy = energy + cost;
+
+            match energy_map.entry(next.clone()) {
+                std::collections::hash_map::Entry::Occupied(mut val)
+                    if val.get() > &next_energy =>
+                {
+                    *val.get_mut() = next_energy;
+                }
+                std::collections::hash_map::Entry::Vacant(e) => {
+                    e.insert(next_energy);
+                }
+                _ => continue,
+            }
+
+            open_set.push(Reverse((next_energy, next)));
+        }
+    }
+
+    bail!("the amphipods can't be organised")
+}
+
+fn part1(burrow: &Burrow) -> Result<usize> {
+    organise(burrow)
+}
+
+/// Unfolds a 2-deep burrow first; one drawn already unfolded is organised as it is.
+fn part2(burrow: &Burrow) -> Result<usize> {
+    if burrow.depth == 2 {
+        organise(&burrow.unfold()?)
+    } else {
+        organise(burrow)
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Burrow;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(burrow: &Self::Input) -> Result<usize> {
+        part1(burrow)
+    }
+
+    fn part2(burrow: &Self::Input) -> Result<usize> {
+        part2(burrow)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = r#"#############
+#...........#
+###B#C#B#D###
+  #A#D#C#A#
+  #########"#;
+
+    #[test]
+    fn part1_works() {
+        assert_eq!(part1(&parse_input(EXAMPLE).unwrap()).unwrap(), 12521);
+    }
+
+    #[test]
+    fn part2_works() {
+        assert_eq!(part2(&parse_input(EXAMPLE).unwrap()).unwrap(), 44169);
+    }
+
+    #[test]
+    fn unfolded_burrows_parse() {
+        let input = r#"#############
+#...........#
+###B#C#B#D###
+  #D#C#B#A#
+  #D#B#A#C#
+  #A#D#C#A#
+  #########"#;
+        let burrow = parse_input(input).unwrap();
+        assert_eq!(burrow, parse_input(EXAMPLE).unwrap().unfold().unwrap());
+        assert_eq!(organise(&burrow).unwrap(), 44169);
+        assert_eq!(part2(&burrow).unwrap(), 44169);
+    }
+
+    #[test]
+    fn organised_burrows_take_no_energy() {
+        let input = "#############\n#...........#\n###A#B#C#D###\n  #A#B#C#D#\n  #########";
+        assert_eq!(part1(&parse_input(input).unwrap()).unwrap(), 0);
+    }
+
+    #[test]
+    fn bad_burrows_are_rejected() {
+        let err = |input| parse_input(input).unwrap_err().to_string();
+        assert_eq!(
+            err("#############\n#...........#\n###B#C#B#D###\n  #A#D#E#A#\n  #########"),
+            "line 4, column 8: expected an amphipod or '.'"
+        );
+        assert_eq!(
+            err("#############\n#..A........#\n###B#C#B#D###\n  #.#D#C#A#\n  #########"),
+            "line 2, column 4: amphipods never stop outside a room"
+        );
+        assert_eq!(
+            err("#############\n#...........#\n###B#C#B#D###\n  #A#D#C#A#"),
+            "line 5, column 1: expected rooms closed off by \"#########\""
+        );
+        assert_eq!(
+            err("#############\n#...........#\n###B#C#B#D###\n  #A#D#C#C#\n  #########"),
+            "there should be 2 amphipods of type A, found 1"
+        );
+        assert_eq!(
+            err("#############\n#A..........#\n###A#B#C#D###\n  #.#B#C#D#\n  #########"),
+            "line 4, column 4: rooms fill from the bottom, not over a gap"
+        );
+    }
+}
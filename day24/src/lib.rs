@@ -0,0 +1,555 @@
+use std::{fmt, str::FromStr};
+
+use anyhow::{anyhow, bail, Context, Error, Result};
+use aoc_common::{
+    parse::{parse_lines, within},
+    ParseError, Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Register {
+    W,
+    X,
+    Y,
+    Z,
+}
+
+impl FromStr for Register {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "w" => Register::W,
+            "x" => Register::X,
+            "y" => Register::Y,
+            "z" => Register::Z,
+            _ => return Err(ParseError::at(s, s, format!("{:?} is not a register", s)).into()),
+        })
+    }
+}
+
+impl fmt::Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Register::W => "w",
+            Register::X => "x",
+            Register::Y => "y",
+            Register::Z => "z",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    Register(Register),
+    Number(i64),
+}
+
+impl FromStr for Operand {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse() {
+            Ok(n) => Ok(Operand::Number(n)),
+            Err(_) => s.parse().map(Operand::Register).map_err(|_| {
+                ParseError::at(s, s, format!("{:?} is not a register or number", s)).into()
+            }),
+        }
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Register(register) => register.fmt(f),
+            Operand::Number(n) => n.fmt(f),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Inp(Register),
+    Add(Register, Operand),
+    Mul(Register, Operand),
+    Div(Register, Operand),
+    Mod(Register, Operand),
+    Eql(Register, Operand),
+}
+
+impl FromStr for Instruction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tokens = s.split_whitespace();
+        let mut next = |what: &str| {
+            tokens.next().ok_or_else(|| {
+                Error::new(ParseError::new(
+                    1,
+                    s.chars().count() + 1,
+                    format!("expected {}", what),
+                ))
+            })
+        };
+
+        let op = next("an instruction")?;
+        let a = within(s, next("a register")?, str::parse)?;
+        let instruction = if op == "inp" {
+            Instruction::Inp(a)
+        } else {
+            let b = within(s, next("a register or number")?, str::parse)?;
+            match op {
+                "add" => Instruction::Add(a, b),
+                "mul" => Instruction::Mul(a, b),
+                "div" => Instruction::Div(a, b),
+                "mod" => Instruction::Mod(a, b),
+                "eql" => Instruction::Eql(a, b),
+                _ => {
+                    return Err(
+                        ParseError::at(s, op, format!("unknown instruction {:?}", op)).into(),
+                    )
+                }
+            }
+        };
+
+        if let Some(extra) = tokens.next() {
+            return Err(ParseError::at(s, extra, "unexpected trailing operand").into());
+        }
+        Ok(instruction)
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Inp(a) => write!(f, "inp {}", a),
+            Instruction::Add(a, b) => write!(f, "add {} {}", a, b),
+            Instruction::Mul(a, b) => write!(f, "mul {} {}", a, b),
+            Instruction::Div(a, b) => write!(f, "div {} {}", a, b),
+            Instruction::Mod(a, b) => write!(f, "mod {} {}", a, b),
+            Instruction::Eql(a, b) => write!(f, "eql {} {}", a, b),
+        }
+    }
+}
+
+/// The arithmetic logic unit's four registers.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Alu {
+    registers: [i64; 4],
+}
+
+impl Alu {
+    pub fn get(&self, register: Register) -> i64 {
+        self.registers[register as usize]
+    }
+
+    fn value(&self, operand: Operand) -> i64 {
+        match operand {
+            Operand::Register(register) => self.get(register),
+            Operand::Number(n) => n,
+        }
+    }
+
+    /// Runs `program`, taking each `inp` from `input`.
+    pub fn run(
+        &mut self,
+        program: &[Instruction],
+        input: impl IntoIterator<Item = i64>,
+    ) -> Result<()> {
+        let mut input = input.into_iter();
+
+        for (i, instruction) in program.iter().enumerate() {
+            let context = || format!("instruction {} ({})", i + 1, instruction);
+            let (a, result) = match *instruction {
+                Instruction::Inp(a) => (a, input.next().context("ran out of input")),
+                Instruction::Add(a, b) => (
+                    a,
+                    self.get(a).checked_add(self.value(b)).context("overflow"),
+                ),
+                Instruction::Mul(a, b) => (
+                    a,
+                    self.get(a).checked_mul(self.value(b)).context("overflow"),
+                ),
+                Instruction::Div(a, b) => (
+                    a,
+                    self.get(a)
+                        .checked_div(self.value(b))
+                        .context("division by zero"),
+                ),
+                Instruction::Mod(a, b) => (
+                    a,
+                    match (self.get(a), self.value(b)) {
+                        (a, b) if a >= 0 && b > 0 => Ok(a % b),
+                        (a, b) => Err(anyhow!("can't take {} mod {}", a, b)),
+                    },
+                ),
+                Instruction::Eql(a, b) => (a, Ok((self.get(a) == self.value(b)) as i64)),
+            };
+            self.registers[a as usize] = result.with_context(context)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The three numbers that make each of MONAD's blocks differ from the others.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Block {
+    div_z: i64,
+    add_x: i64,
+    add_y: i64,
+}
+
+/// The instructions every MONAD block is made of, with `None` where each
+/// block has its own number.
+const BLOCK: [(&str, Register, Option<Operand>); 18] = {
+    use Operand::Number as N;
+    use Operand::Register as R;
+    use Register::*;
+    [
+        ("inp", W, None),
+        ("mul", X, Some(N(0))),
+        ("add", X, Some(R(Z))),
+        ("mod", X, Some(N(26))),
+        ("div", Z, None),
+        ("add", X, None),
+        ("eql", X, Some(R(W))),
+        ("eql", X, Some(N(0))),
+        ("mul", Y, Some(N(0))),
+        ("add", Y, Some(N(25))),
+        ("mul", Y, Some(R(X))),
+        ("add", Y, Some(N(1))),
+        ("mul", Z, Some(R(Y))),
+        ("mul", Y, Some(N(0))),
+        ("add", Y, Some(R(W))),
+        ("add", Y, None),
+        ("mul", Y, Some(R(X))),
+        ("add", Z, Some(R(Y))),
+    ]
+};
+
+/// How many digits a model number has, one per MONAD block.
+const DIGITS: usize = 14;
+
+/// Splits MONAD into its per-digit blocks, checking each has the expected shape.
+fn blocks(program: &[Instruction]) -> Result<Vec<Block>> {
+    if !program.len().is_multiple_of(BLOCK.len()) {
+        bail!(
+            "MONAD is made of {}-instruction blocks, but this program has {} instructions",
+            BLOCK.len(),
+            program.len()
+        );
+    }
+
+    program
+        .chunks(BLOCK.len())
+        .enumerate()
+        .map(|(n, block)| {
+            let mut numbers = vec![];
+            for (i, (instruction, (op, a, b))) in block.iter().zip(BLOCK).enumerate() {
+                let (actual_op, actual_a, actual_b) = match *instruction {
+                    Instruction::Inp(a) => ("inp", a, None),
+                    Instruction::Add(a, b) => ("add", a, Some(b)),
+                    Instruction::Mul(a, b) => ("mul", a, Some(b)),
+                    Instruction::Div(a, b) => ("div", a, Some(b)),
+                    Instruction::Mod(a, b) => ("mod", a, Some(b)),
+                    Instruction::Eql(a, b) => ("eql", a, Some(b)),
+                };
+                let matches = actual_op == op
+                    && actual_a == a
+                    && match (b, actual_b) {
+                        (None, Some(Operand::Number(n))) if op != "inp" => {
+                            numbers.push(n);
+                            true
+                        }
+                        (b, actual_b) => b == actual_b,
+                    };
+                if !matches {
+                    bail!(
+                        "instruction {} ({}) doesn't look like part of MONAD's block {}",
+                        n * BLOCK.len() + i + 1,
+                        instruction,
+                        n + 1
+                    );
+                }
+            }
+
+            match numbers[..] {
+                [div_z @ (1 | 26), add_x, add_y] => Ok(Block {
+                    div_z,
+                    add_x,
+                    add_y,
+                }),
+                _ => bail!("block {} divides z by {}, not 1 or 26", n + 1, numbers[0]),
+            }
+        })
+        .collect()
+}
+
+/// Finds the largest or smallest model number MONAD accepts.
+fn model_number(program: &[Instruction], largest: bool) -> Result<u64> {
+    let blocks = blocks(program)?;
+    if blocks.len() != DIGITS {
+        bail!(
+            "MONAD has a block for each of the {} digits, but this program has {} blocks",
+            DIGITS,
+            blocks.len()
+        );
+    }
+    pick_digits(program, &blocks, largest)
+}
+
+/// Picks the largest or smallest digits, one per block, that `program` accepts.
+///
+/// Each block pushes `digit + add_y` onto a base 26 stack held in `z` when it
+/// divides by 1, and when it divides by 26 pops the top, pushing again
+/// unless `digit == popped + add_x`. `z` ends up 0 only when every popping
+/// block matches, so each pushing block is paired with the block that pops
+/// it, and each pair's digits are picked as large (or small) as that allows.
+fn pick_digits(program: &[Instruction], blocks: &[Block], largest: bool) -> Result<u64> {
+    let mut digits = vec![0; blocks.len()];
+    let mut stack = vec![];
+
+    for (i, block) in blocks.iter().enumerate() {
+        if block.div_z == 1 {
+            stack.push(i);
+            continue;
+        }
+
+        let j = stack
+            .pop()
+            .with_context(|| format!("block {} pops z when nothing was pushed", i + 1))?;
+        // digits[i] == digits[j] + offset
+        let offset = blocks[j].add_y + block.add_x;
+        if offset.abs() > 8 {
+            bail!(
+                "no digits for blocks {} and {} differ by {}",
+                j + 1,
+                i + 1,
+                offset
+            );
+        }
+        digits[j] = if largest {
+            9.min(9 - offset)
+        } else {
+            1.max(1 - offset)
+        };
+        digits[i] = digits[j] + offset;
+    }
+    if let Some(j) = stack.pop() {
+        bail!("block {} pushes onto z but nothing pops it", j + 1);
+    }
+
+    let mut alu = Alu::default();
+    alu.run(program, digits.iter().copied())?;
+    if alu.get(Register::Z) != 0 {
+        bail!("MONAD rejected {:?}", digits);
+    }
+
+    Ok(digits.iter().fold(0, |n, &digit| n * 10 + digit as u64))
+}
+
+fn parse_input(input: &str) -> Result<Vec<Instruction>> {
+    parse_lines(input, str::parse)
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<Instruction>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(program: &Self::Input) -> Result<u64> {
+        model_number(program, true)
+    }
+
+    fn part2(program: &Self::Input) -> Result<u64> {
+        model_number(program, false)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn run(program: &str, input: &[i64]) -> Alu {
+        let mut alu = Alu::default();
+        alu.run(&parse_input(program).unwrap(), input.iter().copied())
+            .unwrap();
+        alu
+    }
+
+    /// A MONAD-shaped program made of one block per `(div_z, add_x, add_y)`.
+    fn monad(blocks: &[(i64, i64, i64)]) -> String {
+        blocks
+            .iter()
+            .map(|(div_z, add_x, add_y)| {
+                format!(
+                    "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {}\nadd x {}\neql x w\neql x 0\n\
+                     mul y 0\nadd y 25\nmul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\nadd y {}\n\
+                     mul y x\nadd z y\n",
+                    div_z, add_x, add_y
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn negation_works() {
+        assert_eq!(run("inp x\nmul x -1", &[7]).get(Register::X), -7);
+    }
+
+    #[test]
+    fn comparison_works() {
+        let program = "inp z\ninp x\nmul z 3\neql z x";
+        assert_eq!(run(program, &[2, 6]).get(Register::Z), 1);
+        assert_eq!(run(program, &[2, 7]).get(Register::Z), 0);
+    }
+
+    #[test]
+    fn binary_works() {
+        let program = "inp w\nadd z w\nmod z 2\ndiv w 2\nadd y w\nmod y 2\ndiv w 2\n\
+                       add x w\nmod x 2\ndiv w 2\nmod w 2";
+        let alu = run(program, &[11]);
+        assert_eq!(
+            [Register::W, Register::X, Register::Y, Register::Z].map(|r| alu.get(r)),
+            [1, 0, 1, 1]
+        );
+    }
+
+    #[test]
+    fn bad_operations_are_reported() {
+        let mut alu = Alu::default();
+        let program = parse_input("inp x\ndiv x y").unwrap();
+        assert_eq!(
+            format!("{:#}", alu.run(&program, [1]).unwrap_err()),
+            "instruction 2 (div x y): division by zero"
+        );
+        assert_eq!(
+            format!("{:#}", alu.run(&program, []).unwrap_err()),
+            "instruction 1 (inp x): ran out of input"
+        );
+
+        let program = parse_input("inp x\nmul x 9223372036854775807\nmul x 2").unwrap();
+        assert_eq!(
+            format!("{:#}", alu.run(&program, [1]).unwrap_err()),
+            "instruction 3 (mul x 2): overflow"
+        );
+        let program = parse_input("inp x\nadd x 9223372036854775807").unwrap();
+        assert_eq!(
+            format!("{:#}", alu.run(&program, [1]).unwrap_err()),
+            "instruction 2 (add x 9223372036854775807): overflow"
+        );
+    }
+
+    #[test]
+    fn bad_instructions_are_reported() {
+        let err = |input| parse_input(input).unwrap_err().to_string();
+        assert_eq!(
+            err("inp w\nadd q 1"),
+            "line 2, column 5: \"q\" is not a register"
+        );
+        assert_eq!(
+            err("inp w\nadd x"),
+            "line 2, column 6: expected a register or number"
+        );
+        assert_eq!(
+            err("sub x 1"),
+            "line 1, column 1: unknown instruction \"sub\""
+        );
+        assert_eq!(
+            err("inp w 3"),
+            "line 1, column 7: unexpected trailing operand"
+        );
+    }
+
+    #[test]
+    fn model_numbers_are_found() {
+        let program = parse_input(&monad(&[
+            (1, 11, 6),
+            (1, 13, 14),
+            (1, 15, 14),
+            (26, -8, 10),
+            (1, 13, 9),
+            (1, 15, 12),
+            (26, -11, 8),
+            (26, -4, 13),
+            (26, -15, 12),
+            (1, 14, 6),
+            (1, 14, 9),
+            (26, -1, 15),
+            (26, -8, 4),
+            (26, -14, 10),
+        ]))
+        .unwrap();
+        assert_eq!(model_number(&program, true).unwrap(), 99394899891971);
+        assert_eq!(model_number(&program, false).unwrap(), 92171126131911);
+    }
+
+    #[test]
+    fn stack_analysis_matches_brute_force() {
+        let program =
+            parse_input(&monad(&[(1, 12, 4), (1, 10, 2), (26, -3, 7), (26, -6, 1)])).unwrap();
+        let blocks = blocks(&program).unwrap();
+        let accepted = (1111..=9999)
+            .filter(|n: &u64| !n.to_string().contains('0'))
+            .filter(|n| {
+                let digits = n
+                    .to_string()
+                    .bytes()
+                    .map(|b| (b - b'0') as i64)
+                    .collect::<Vec<_>>();
+                let mut alu = Alu::default();
+                alu.run(&program, digits).unwrap();
+                alu.get(Register::Z) == 0
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            pick_digits(&program, &blocks, true).unwrap(),
+            *accepted.last().unwrap()
+        );
+        assert_eq!(pick_digits(&program, &blocks, false).unwrap(), accepted[0]);
+    }
+
+    #[test]
+    fn non_monad_programs_are_rejected() {
+        assert_eq!(
+            model_number(&[], true).unwrap_err().to_string(),
+            "MONAD has a block for each of the 14 digits, but this program has 0 blocks"
+        );
+        let program = parse_input(&monad(&[(1, 12, 4), (26, -3, 7)])).unwrap();
+        assert_eq!(
+            model_number(&program, true).unwrap_err().to_string(),
+            "MONAD has a block for each of the 14 digits, but this program has 2 blocks"
+        );
+
+        let mut program = parse_input(&monad(&[(1, 12, 4), (26, -3, 7)])).unwrap();
+        program[20] = Instruction::Mul(Register::X, Operand::Number(1));
+        assert_eq!(
+            model_number(&program, true).unwrap_err().to_string(),
+            "instruction 21 (mul x 1) doesn't look like part of MONAD's block 2"
+        );
+
+        let program = parse_input(&monad(&[(1, 12, 4), (1, -3, 7)])).unwrap();
+        assert_eq!(
+            pick_digits(&program, &blocks(&program).unwrap(), true)
+                .unwrap_err()
+                .to_string(),
+            "block 2 pushes onto z but nothing pops it"
+        );
+
+        let program = parse_input(&monad(&[(1, 12, 4), (26, -15, 7)])).unwrap();
+        assert_eq!(
+            pick_digits(&program, &blocks(&program).unwrap(), true)
+                .unwrap_err()
+                .to_string(),
+            "no digits for blocks 1 and 2 differ by -11"
+        );
+    }
+}
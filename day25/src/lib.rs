@@ -0,0 +1,178 @@
+use anyhow::{bail, Context, Error, Result};
+use aoc_common::{Grid, Solution};
+use std::{fmt::Display, str::FromStr};
+
+#[derive(Eq, PartialEq, Copy, Clone)]
+enum Cuke {
+    South,
+    East,
+}
+
+impl Cuke {
+    fn apply(
+        &self,
+        (x, y): (usize, usize),
+        map: &Grid<Tile>,
+    ) -> Option<((usize, usize), (usize, usize))> {
+        let next = match self {
+            Cuke::South => (x, (y + 1) % map.height()),
+            Cuke::East => ((x + 1) % map.width(), y),
+        };
+
+        if let Tile { occupant: Some(_) } = map[next] {
+            None
+        } else {
+            Some(((x, y), next))
+        }
+    }
+}
+
+impl FromStr for Cuke {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            ">" => Cuke::East,
+            "v" => Cuke::South,
+            _ => return None.with_context(|| format!("Unknown cuke {:?}", s)),
+        })
+    }
+}
+
+struct Tile {
+    occupant: Option<Cuke>,
+}
+
+impl FromStr for Tile {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "." => Self { occupant: None },
+            occupied => Self {
+                occupant: Some(occupied.parse()?),
+            },
+        })
+    }
+}
+
+struct Map {
+    inner: Grid<Tile>,
+}
+
+impl FromStr for Map {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let inner = Grid::parse_with(s, |c| c.to_string().parse())?;
+
+        Ok(Self { inner })
+    }
+}
+
+impl Map {
+    fn step(&mut self) -> usize {
+        fn change_direction(map: &mut Map, cuke_type: Cuke) -> usize {
+            let mut changes = vec![];
+
+            for (coord, tile) in map.inner.iter() {
+                if tile.occupant == Some(cuke_type) {
+                    if let Some(Some(change)) = tile
+                        .occupant
+                        .as_ref()
+                        .map(|occupant| occupant.apply(coord, &map.inner))
+                    {
+                        changes.push(change);
+                    }
+                }
+            }
+
+            let changes_count = changes.len();
+
+            for (before, after) in changes {
+                let occupant = map.inner[before].occupant.take();
+                map.inner[after].occupant = occupant;
+            }
+
+            changes_count
+        }
+
+        change_direction(self, Cuke::East) + change_direction(self, Cuke::South)
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let result = self
+            .inner
+            .rows()
+            .map(|line| {
+                line.iter()
+                    .map(|tile| match tile {
+                        Tile {
+                            occupant: Some(Cuke::South),
+                        } => 'v',
+                        Tile {
+                            occupant: Some(Cuke::East),
+                        } => '>',
+                        _ => '.',
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        write!(f, "{}", result)
+    }
+}
+
+fn part1(input: &str) -> Result<usize> {
+    let mut map = input.parse::<Map>()?;
+    let mut count = 1;
+
+    while map.step() > 0 {
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const HAS_PART2: bool = false;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.to_owned())
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        part1(input)
+    }
+
+    fn part2(_input: &Self::Input) -> Result<usize> {
+        bail!("day 25 has no part 2")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn part1_works() {
+        let input = r#"v...>>.vv>
+.vv>>.vv..
+>>.>v>...v
+>>v>>.>.v.
+v>v.vv.v..
+>.>>..v...
+.vv..>.>v.
+v.v..>>v.v
+....v..v.>"#;
+        assert_eq!(part1(input).unwrap(), 58)
+    }
+}
@@ -0,0 +1,173 @@
+use std::cmp::Ordering;
+
+use anyhow::{Context, Result};
+use aoc_common::{bits::fold_bit, parse::parse_lines, ParseError, Solution};
+
+/// The diagnostic report: one or more equally long binary numbers of at most 32 bits.
+#[derive(Debug)]
+pub struct Report {
+    width: usize,
+    numbers: Vec<Vec<bool>>,
+}
+
+fn parse_report(input: &str) -> Result<Report> {
+    let width = match input.lines().next() {
+        Some(line) if !line.is_empty() => line.len(),
+        _ => return Err(ParseError::new(1, 1, "expected a binary number").into()),
+    };
+    if width > 32 {
+        return Err(ParseError::new(1, 33, "numbers can be at most 32 bits").into());
+    }
+
+    let numbers = parse_lines(input, |line| {
+        if let Some((i, c)) = line.char_indices().find(|(_, c)| !matches!(c, '0' | '1')) {
+            return Err(ParseError::at(line, &line[i..], format!("{:?} is not a bit", c)).into());
+        }
+        if line.len() != width {
+            return Err(ParseError::new(
+                1,
+                line.len().min(width) + 1,
+                format!("expected {} bits, found {}", width, line.len()),
+            )
+            .into());
+        }
+        Ok(line.chars().map(|c| c == '1').collect())
+    })?;
+
+    Ok(Report { width, numbers })
+}
+
+fn part1(report: &Report) -> usize {
+    let counts = report
+        .numbers
+        .iter()
+        .fold(vec![0; report.width], |mut memo, number| {
+            for (i, bit) in number.iter().enumerate() {
+                if *bit {
+                    memo[i] += 1;
+                }
+            }
+            memo
+        });
+
+    let line_mid = report.numbers.len() / 2;
+    let bits = counts.iter().map(|count| count > &line_mid);
+
+    let gamma = bits.clone().fold(0, fold_bit);
+    let epsilon = bits.map(|bit| !bit).fold(0, fold_bit);
+
+    gamma * epsilon
+}
+
+fn part2(report: &Report) -> Result<u64> {
+    fn ones_at_index(numbers: &[&[bool]], index: usize) -> Ordering {
+        let total = numbers.len();
+
+        let sub_count = numbers.iter().filter(|number| number[index]).count();
+
+        let mut line_mid = total / 2;
+        if total % 2 == 1 {
+            line_mid += 1;
+        }
+
+        sub_count.cmp(&line_mid)
+    }
+
+    fn find_line(report: &Report, func: impl Fn(Ordering) -> bool) -> Result<u64> {
+        let mut numbers = report.numbers.iter().map(Vec::as_slice).collect::<Vec<_>>();
+        for current_position in 0..report.width {
+            let bit = (func)(ones_at_index(&numbers, current_position));
+            let next = numbers
+                .iter()
+                .filter(|number| number[current_position] == bit)
+                .cloned()
+                .collect::<Vec<_>>();
+
+            if next.is_empty() {
+                break;
+            }
+            numbers = next;
+        }
+
+        let number = numbers.last().context("the report has no numbers")?;
+        Ok(number.iter().copied().fold(0, fold_bit) as u64)
+    }
+
+    let oxygen = find_line(report, |ord| match ord {
+        Ordering::Less => false,
+        Ordering::Equal | Ordering::Greater => true,
+    })?;
+    let co2 = find_line(report, |ord| match ord {
+        Ordering::Less => true,
+        Ordering::Equal | Ordering::Greater => false,
+    })?;
+
+    // each rating can take up to 32 bits, so their product needs 64
+    Ok(oxygen * co2)
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Report;
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_report(input)
+    }
+
+    fn part1(report: &Self::Input) -> Result<usize> {
+        Ok(part1(report))
+    }
+
+    fn part2(report: &Self::Input) -> Result<u64> {
+        part2(report)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn part2_works() {
+        let input = r#"00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010"#;
+        let report = Day3::parse(input).unwrap();
+        assert_eq!(part1(&report), 198);
+        assert_eq!(part2(&report).unwrap(), 230)
+    }
+
+    #[test]
+    fn widest_numbers_dont_overflow() {
+        let input =
+            Day3::parse("11111111111111111111111111111111\n11111111111111111111111111111110")
+                .unwrap();
+        assert_eq!(Day3::part2(&input).unwrap(), 4294967295 * 4294967294);
+    }
+
+    #[test]
+    fn bad_reports_are_rejected() {
+        let err = Day3::parse("00100\n11120").unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 4: '2' is not a bit");
+
+        let err = Day3::parse("00100\n1111").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 5: expected 5 bits, found 4"
+        );
+
+        assert!(Day3::parse("").is_err());
+    }
+}
@@ -1,6 +1,8 @@
+use aoc_common::ParseError;
+
 use crate::Error;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Board {
     tiles: Vec<Tile>,
 }
@@ -10,9 +12,21 @@ impl std::str::FromStr for Board {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let tiles = s
-            .lines()
-            .flat_map(|line| line.trim().split_whitespace().map(|num| num.parse()))
-            .collect::<Result<_, _>>()?;
+            .split_whitespace()
+            .map(|num| {
+                num.parse()
+                    .map_err(|_| ParseError::at(s, num, format!("{:?} is not a tile", num)))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if tiles.len() != WIDTH * WIDTH {
+            return Err(ParseError::at(
+                s,
+                s,
+                format!("expected {} tiles, found {}", WIDTH * WIDTH, tiles.len()),
+            )
+            .into());
+        }
         Ok(Self { tiles })
     }
 }
@@ -26,7 +40,7 @@ impl Board {
             .find(|(_, tile)| tile.num == *call)?;
 
         tile.state = tile.state.transition();
-        self.check_win(index).then(|| *call)
+        self.check_win(index).then_some(*call)
     }
 
     fn check_win(&self, index: usize) -> bool {
@@ -79,26 +93,24 @@ impl Board {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Tile {
     state: State,
     num: u32,
 }
 
 impl std::str::FromStr for Tile {
-    type Err = Error;
+    type Err = std::num::ParseIntError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
             state: State::Unmarked,
-            num: s
-                .parse()
-                .map_err(|_| Error::ParseError(format!("Couldn't parse tile: {}", s)))?,
+            num: s.parse()?,
         })
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 enum State {
     Marked,
     Unmarked,
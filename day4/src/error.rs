@@ -1,4 +1,20 @@
 #[derive(Debug)]
 pub enum Error {
-    ParseError(String),
+    ParseError(aoc_common::ParseError),
 }
+
+impl From<aoc_common::ParseError> for Error {
+    fn from(e: aoc_common::ParseError) -> Self {
+        Error::ParseError(e)
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::ParseError(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
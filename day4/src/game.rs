@@ -1,5 +1,8 @@
+use aoc_common::ParseError;
+
 use crate::{board::Board, Error};
 
+#[derive(Debug, Clone)]
 pub struct Game {
     calls: Vec<u32>,
     boards: Vec<Board>,
@@ -11,22 +14,25 @@ impl std::str::FromStr for Game {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let (calls, rest) = s
             .split_once("\n\n")
-            .ok_or_else(|| Error::ParseError("couldn't get moves".into()))?;
+            .ok_or_else(|| ParseError::at(s, s, "expected calls, a blank line, then boards"))?;
+        let calls = calls
+            .split(',')
+            .map(|call| {
+                call.parse()
+                    .map_err(|_| ParseError::at(s, call, format!("{:?} is not a call", call)))
+            })
+            .collect::<Result<_, _>>()?;
         let boards = rest
             .split("\n\n")
-            .map(|board| board.parse())
+            .filter(|board| !board.trim().is_empty())
+            .map(|board| {
+                board
+                    .parse()
+                    .map_err(|Error::ParseError(e)| Error::ParseError(e.relative_to(s, board)))
+            })
             .collect::<Result<Vec<_>, Error>>()?;
 
-        Ok(Self {
-            calls: calls
-                .split(',')
-                .map(|call| {
-                    call.parse()
-                        .map_err(|_| Error::ParseError("can't read call".into()))
-                })
-                .collect::<Result<_, _>>()?,
-            boards,
-        })
+        Ok(Self { calls, boards })
     }
 }
 
@@ -67,3 +73,24 @@ impl Game {
         score
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bad_games_are_reported() {
+        let err = "7,4,x\n\n1 2 3 4 5".parse::<Game>().unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 5: \"x\" is not a call");
+
+        let input = "7,4\n\n 1  2  3  4  5\n 6  7  8  9 10\n11 12 13 14 15\n16 17 18 19 20\n21 22 23 24 25\n\n 1  2  3\n 4  y  6";
+        let err = input.parse::<Game>().unwrap_err();
+        assert_eq!(err.to_string(), "line 10, column 5: \"y\" is not a tile");
+
+        let err = "7,4\n\n1 2 3 4 5".parse::<Game>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 1: expected 25 tiles, found 5"
+        );
+    }
+}
@@ -0,0 +1,29 @@
+mod error;
+pub use error::Error;
+
+mod game;
+pub use game::Game;
+
+mod board;
+
+use aoc_common::Solution;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Game;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.parse::<Game>()?)
+    }
+
+    fn part1(game: &Self::Input) -> anyhow::Result<u32> {
+        Ok(game.clone().part1())
+    }
+
+    fn part2(game: &Self::Input) -> anyhow::Result<u32> {
+        Ok(game.clone().part2())
+    }
+}
@@ -0,0 +1,240 @@
+use std::ops::Sub;
+
+use anyhow::{Error, Result};
+use aoc_common::{
+    parse::{parse_lines, within},
+    ParseError, Solution,
+};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
+struct Coord(usize, usize);
+
+impl Sub for Coord {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0, self.1 - rhs.1)
+    }
+}
+
+impl std::str::FromStr for Coord {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = s
+            .split_once(',')
+            .ok_or_else(|| ParseError::at(s, s, "expected `x,y`"))?;
+        let parse = |n: &str| {
+            n.parse::<usize>()
+                .map_err(|_| ParseError::at(s, n, format!("{:?} is not a coordinate", n)))
+        };
+        Ok(Self(parse(x)?, parse(y)?))
+    }
+}
+
+#[derive(Debug)]
+pub struct Vent {
+    start: Coord,
+    end: Coord,
+}
+
+impl Vent {
+    fn is_straight(&self) -> bool {
+        matches!(
+            (self.end.0 == self.start.0, self.end.1 == self.start.1),
+            (true, _) | (_, true)
+        )
+    }
+
+    fn all_coords(&self) -> Box<dyn Iterator<Item = Coord>> {
+        let Coord(mut start_x, mut start_y) = self.start;
+        let Coord(mut end_x, mut end_y) = self.end;
+
+        if self.is_straight() {
+            if start_x > end_x {
+                std::mem::swap(&mut start_x, &mut end_x)
+            }
+
+            if start_y > end_y {
+                std::mem::swap(&mut start_y, &mut end_y)
+            }
+
+            Box::new(
+                (start_x..=end_x).flat_map(move |x| (start_y..=end_y).map(move |y| Coord(x, y))),
+            )
+        } else {
+            let x_range: Box<dyn Iterator<Item = usize>> = match start_x.cmp(&end_x) {
+                std::cmp::Ordering::Less => Box::new(start_x..=end_x),
+                std::cmp::Ordering::Greater => Box::new((end_x..=start_x).rev()),
+                std::cmp::Ordering::Equal => unreachable!(),
+            };
+
+            let y_range: Box<dyn Iterator<Item = usize>> = match start_y.cmp(&end_y) {
+                std::cmp::Ordering::Less => Box::new(start_y..=end_y),
+                std::cmp::Ordering::Greater => Box::new((end_y..=start_y).rev()),
+                std::cmp::Ordering::Equal => unreachable!(),
+            };
+
+            Box::new((x_range).zip(y_range).map(|(x, y)| Coord(x, y)))
+        }
+    }
+}
+
+impl std::str::FromStr for Vent {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (left, right) = s
+            .split_once(" -> ")
+            .ok_or_else(|| ParseError::at(s, s, "expected `x1,y1 -> x2,y2`"))?;
+
+        let vent = Self {
+            start: within(s, left, str::parse)?,
+            end: within(s, right, str::parse)?,
+        };
+
+        let (dx, dy) = (
+            vent.start.0.abs_diff(vent.end.0),
+            vent.start.1.abs_diff(vent.end.1),
+        );
+        if dx != 0 && dy != 0 && dx != dy {
+            return Err(ParseError::at(s, s, "vents must be straight or at 45 degrees").into());
+        }
+        Ok(vent)
+    }
+}
+
+struct StraightMap {
+    inner: std::collections::HashMap<Coord, usize>,
+}
+
+impl StraightMap {
+    fn find_greater_than_one(&self) -> usize {
+        self.inner.iter().filter(|(_, &count)| count > 1).count()
+    }
+}
+
+impl From<&[Vent]> for StraightMap {
+    fn from(vents: &[Vent]) -> Self {
+        let mut inner = std::collections::HashMap::new();
+
+        vents
+            .iter()
+            .filter(|vent| vent.is_straight())
+            .flat_map(|vent| vent.all_coords())
+            .for_each(|coord| {
+                *inner.entry(coord).or_insert(0) += 1;
+            });
+
+        Self { inner }
+    }
+}
+
+struct DiagonalMap {
+    inner: std::collections::HashMap<Coord, usize>,
+}
+
+impl DiagonalMap {
+    fn find_greater_than_one(&self) -> usize {
+        self.inner.iter().filter(|(_, &count)| count > 1).count()
+    }
+}
+
+impl From<&[Vent]> for DiagonalMap {
+    fn from(vents: &[Vent]) -> Self {
+        let mut inner = std::collections::HashMap::new();
+
+        vents
+            .iter()
+            .flat_map(|vent| vent.all_coords())
+            .for_each(|coord| {
+                *inner.entry(coord).or_insert(0) += 1;
+            });
+
+        Self { inner }
+    }
+}
+
+fn part1(vents: &[Vent]) -> usize {
+    StraightMap::from(vents).find_greater_than_one()
+}
+
+fn part2(vents: &[Vent]) -> usize {
+    DiagonalMap::from(vents).find_greater_than_one()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Vec<Vent>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_lines(input, str::parse)
+    }
+
+    fn part1(vents: &Self::Input) -> Result<usize> {
+        Ok(part1(vents))
+    }
+
+    fn part2(vents: &Self::Input) -> Result<usize> {
+        Ok(part2(vents))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn part1_works() {
+        let input = r#"0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2"#;
+        assert_eq!(part1(&Day5::parse(input).unwrap()), 5)
+    }
+
+    #[test]
+    fn part2_works() {
+        let input = r#"0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2"#;
+        assert_eq!(part2(&Day5::parse(input).unwrap()), 12)
+    }
+
+    #[test]
+    fn bad_vents_are_reported() {
+        let err = Day5::parse("0,9 -> 5,9\n8,0 -> 0,x").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 10: \"x\" is not a coordinate"
+        );
+
+        let err = Day5::parse("0,9 => 5,9").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: expected `x1,y1 -> x2,y2`"
+        );
+
+        let err = Day5::parse("0,0 -> 1,2").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: vents must be straight or at 45 degrees"
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use std::str::FromStr;
+
+use anyhow::Error;
+use aoc_common::{ParseError, Solution};
+
+#[derive(Debug, Clone)]
+pub struct Sim {
+    fish_groups: [usize; 7],
+    day1_fish: usize,
+    day2_fish: usize,
+}
+
+impl Sim {
+    fn run(&mut self, steps: usize) -> usize {
+        for _ in 0..steps {
+            let new_fish = self.fish_groups[0];
+
+            self.fish_groups.rotate_left(1);
+            self.fish_groups[6] += self.day2_fish;
+            self.day2_fish = self.day1_fish;
+            self.day1_fish = new_fish;
+        }
+
+        self.fish_groups
+            .iter()
+            .chain([self.day1_fish, self.day2_fish].iter())
+            .sum()
+    }
+}
+
+impl FromStr for Sim {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fish_groups = [0usize; 7];
+        let fishes = s
+            .split(',')
+            .map(|num| match num.parse::<usize>() {
+                Ok(fish) if fish < fish_groups.len() => Ok(fish),
+                _ => Err(ParseError::at(
+                    s,
+                    num,
+                    format!("{:?} is not a timer from 0 to 6", num),
+                )),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for fish in fishes {
+            fish_groups[fish] += 1;
+        }
+
+        Ok(Self {
+            day1_fish: 0,
+            day2_fish: 0,
+            fish_groups,
+        })
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Sim;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        input.trim_end().parse::<Sim>()
+    }
+
+    fn part1(sim: &Self::Input) -> anyhow::Result<usize> {
+        Ok(sim.clone().run(80))
+    }
+
+    fn part2(sim: &Self::Input) -> anyhow::Result<usize> {
+        Ok(sim.clone().run(256))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn part1_works() {
+        let input = r#"3,4,3,1,2"#;
+        let mut sim = input.trim().parse::<Sim>().unwrap();
+        assert_eq!(5934, sim.run(80));
+    }
+
+    #[test]
+    fn bad_timers_are_reported() {
+        let err = Day6::parse("3,4,3,7,2\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 7: \"7\" is not a timer from 0 to 6"
+        );
+    }
+}
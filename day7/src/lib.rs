@@ -0,0 +1,103 @@
+use anyhow::{Context, Result};
+use aoc_common::{ParseError, Solution};
+
+fn parse_input(input: &str) -> Result<Vec<u32>> {
+    let input = input.trim_end();
+    if input.is_empty() {
+        return Err(ParseError::new(1, 1, "expected crab positions").into());
+    }
+
+    Ok(input
+        .split(',')
+        .map(|num| {
+            num.parse::<u32>()
+                .map_err(|_| ParseError::at(input, num, format!("{:?} is not a position", num)))
+        })
+        .collect::<Result<Vec<_>, _>>()?)
+}
+
+fn part1(nums: &[u32]) -> Result<u32> {
+    let avg = (nums.iter().sum::<u32>() as f64 / nums.len() as f64).round() as u32;
+
+    const SLOP: f64 = 0.6;
+    let candidates = (avg - ((avg as f64 * SLOP) as u32)..(avg + (avg as f64 * SLOP) as u32))
+        .map(|alignment_point| {
+            nums.iter()
+                .map(|i| (alignment_point as i32 - *i as i32).unsigned_abs())
+                .sum::<u32>()
+        })
+        .collect::<Vec<_>>();
+
+    candidates
+        .into_iter()
+        .min()
+        .context("the crabs are too close to zero to estimate an alignment")
+}
+
+fn part2(nums: &[u32]) -> Result<u32> {
+    let avg = (nums.iter().sum::<u32>() as f64 / nums.len() as f64).round() as u32;
+
+    const SLOP: f64 = 0.6;
+    let candidates = (avg - ((avg as f64 * SLOP) as u32)..(avg + (avg as f64 * SLOP) as u32))
+        .map(|alignment_point| {
+            nums.iter()
+                .map(|i| {
+                    let base = (alignment_point as i32 - *i as i32).unsigned_abs();
+                    (0..=base).sum::<u32>()
+                })
+                .sum::<u32>()
+        })
+        .collect::<Vec<_>>();
+
+    candidates
+        .into_iter()
+        .min()
+        .context("the crabs are too close to zero to estimate an alignment")
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<u32>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<u32> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<u32> {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn part1_works() {
+        let input = "16,1,2,0,4,2,7,1,2,14";
+        assert_eq!(part1(&parse_input(input).unwrap()).unwrap(), 37)
+    }
+
+    #[test]
+    fn part2_works() {
+        let input = "16,1,2,0,4,2,7,1,2,14";
+        assert_eq!(part2(&parse_input(input).unwrap()).unwrap(), 168)
+    }
+
+    #[test]
+    fn bad_positions_are_reported() {
+        let err = parse_input("16,1,-2\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 6: \"-2\" is not a position"
+        );
+        assert!(parse_input("\n").is_err());
+    }
+}
@@ -0,0 +1,194 @@
+use std::collections::HashSet;
+
+use anyhow::Result;
+use aoc_common::{parse::parse_lines, ParseError, Solution};
+
+/// One line of notes, with the four output patterns decoded to digits.
+#[derive(Debug)]
+pub struct Entry {
+    outputs: Vec<usize>,
+}
+
+fn parse_entry(line: &str) -> Result<Entry> {
+    let (nums, right) = line
+        .split_once(" | ")
+        .ok_or_else(|| ParseError::at(line, line, "expected `<patterns> | <outputs>`"))?;
+
+    let pattern = |num: &str| {
+        if let Some((i, c)) = num.char_indices().find(|(_, c)| !('a'..='g').contains(c)) {
+            return Err(ParseError::at(
+                line,
+                &num[i..],
+                format!("{:?} is not a segment", c),
+            ));
+        }
+        Ok(num.chars().collect::<HashSet<char>>())
+    };
+
+    let mut nums = nums
+        .split_whitespace()
+        .map(pattern)
+        .collect::<Result<Vec<_>, _>>()?;
+    if nums.len() != 10 {
+        return Err(ParseError::at(
+            line,
+            line,
+            format!("expected 10 patterns, found {}", nums.len()),
+        )
+        .into());
+    }
+
+    let mut take = |len: usize, name: &str| {
+        nums.iter()
+            .position(|num| num.len() == len)
+            .map(|i| nums.remove(i))
+            .ok_or_else(|| {
+                ParseError::at(line, line, format!("couldn't find a pattern for {}", name))
+            })
+    };
+    let one = take(2, "one")?;
+    let four = take(4, "four")?;
+    let seven = take(3, "seven")?;
+    let eight = take(7, "eight")?;
+    nums.sort_by_key(|num| num.len());
+
+    let find_num =
+        |haystack: &'_ [HashSet<char>], condition: &dyn Fn(&&HashSet<char>) -> bool, num: &str| {
+            let mut iter = haystack.iter().filter(condition);
+
+            match (iter.next(), iter.next()) {
+                (Some(val), None) => Ok(val.clone()),
+                (Some(_), Some(_)) => Err(ParseError::at(
+                    line,
+                    line,
+                    format!("found too many patterns for {}", num),
+                )),
+                (None, _) => Err(ParseError::at(
+                    line,
+                    line,
+                    format!("couldn't find a pattern for {}", num),
+                )),
+            }
+        };
+
+    let (five_counts, six_counts) = nums.split_at(nums.partition_point(|num| num.len() < 6));
+
+    let six_test = eight.difference(&seven).cloned().collect::<HashSet<char>>();
+    let six = find_num(
+        six_counts,
+        &|num| six_test.difference(num).count() == 0,
+        "six",
+    )?;
+    let nine = find_num(six_counts, &|num| four.difference(num).count() == 0, "nine")?;
+    let zero = find_num(six_counts, &|num| num != &&six && num != &&nine, "zero")?;
+
+    let five = find_num(five_counts, &|num| six.difference(num).count() == 1, "five")?;
+    let three = find_num(
+        five_counts,
+        &|num| five.difference(num).count() == 1,
+        "three",
+    )?;
+    let two = find_num(five_counts, &|num| num != &&three && num != &&five, "two")?;
+
+    let num_map = [zero, one, two, three, four, five, six, seven, eight, nine];
+
+    let outputs = right
+        .split_whitespace()
+        .map(|num| {
+            let chars = pattern(num)?;
+
+            num_map
+                .iter()
+                .position(|candidate| &chars == candidate)
+                .ok_or_else(|| ParseError::at(line, num, format!("{:?} isn't any digit", num)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Entry { outputs })
+}
+
+fn part1(entries: &[Entry]) -> usize {
+    entries
+        .iter()
+        .map(|entry| {
+            entry
+                .outputs
+                .iter()
+                .filter(|digit| matches!(digit, 1 | 4 | 7 | 8))
+                .count()
+        })
+        .sum()
+}
+
+fn part2(entries: &[Entry]) -> usize {
+    entries
+        .iter()
+        .map(|entry| {
+            entry.outputs.iter().fold(0, |mut memo, num| {
+                memo *= 10;
+                memo += num;
+                memo
+            })
+        })
+        .sum()
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Vec<Entry>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_lines(input, parse_entry)
+    }
+
+    fn part1(entries: &Self::Input) -> Result<usize> {
+        Ok(part1(entries))
+    }
+
+    fn part2(entries: &Self::Input) -> Result<usize> {
+        Ok(part2(entries))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn part2_works() {
+        let input = r#"be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce"#;
+        assert_eq!(part2(&Day8::parse(input).unwrap()), 61229);
+    }
+
+    #[test]
+    fn bad_entries_are_reported() {
+        let line = "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb";
+
+        let err = Day8::parse(&format!("{} | fdgacbe cefdb cefbgd gcbh", line)).unwrap_err();
+        assert_eq!(err.to_string(), "line 1, column 86: 'h' is not a segment");
+
+        let err = Day8::parse(&format!("{} | fdgacbe cefdb cefbgd gcb", line)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 83: \"gcb\" isn't any digit"
+        );
+
+        let err = Day8::parse(&format!("{} fdgacbe cefdb", line)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: expected `<patterns> | <outputs>`"
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use std::collections::HashSet;
+
+use anyhow::Result;
+use aoc_common::{Grid, Solution};
+
+fn part1(map: &Grid<u8>) -> Result<u32> {
+    let mut risk_level = 0;
+    for (coord, cell) in map.iter() {
+        if map.neighbours4(coord).all(|other| cell < &map[other]) {
+            risk_level += 1 + *cell as u32;
+        }
+    }
+
+    Ok(risk_level)
+}
+
+fn part2(map: &Grid<u8>) -> Result<u32> {
+    let mut seen: HashSet<(usize, usize)> = HashSet::new();
+    let mut groups = vec![];
+
+    for (coord, cell) in map.iter() {
+        if seen.contains(&coord) {
+            continue;
+        }
+        if cell == &9 {
+            seen.insert(coord);
+            continue;
+        }
+
+        let mut candidates = vec![coord];
+        let mut count = 0;
+
+        while let Some(coord) = candidates.pop() {
+            if !seen.insert(coord) {
+                continue;
+            }
+            if map[coord] == 9 {
+                continue;
+            }
+            count += 1;
+            candidates.extend(map.neighbours4(coord));
+        }
+
+        groups.push(count)
+    }
+
+    groups.sort_unstable();
+    groups.reverse();
+    Ok(groups.iter().take(3).product())
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Grid<u8>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Grid::parse_digits(input)
+    }
+
+    fn part1(map: &Self::Input) -> Result<u32> {
+        part1(map)
+    }
+
+    fn part2(map: &Self::Input) -> Result<u32> {
+        part2(map)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn part2_works() {
+        let input = "1239123";
+        let map = Grid::parse_digits(input).unwrap();
+        dbg!(&map);
+        assert_eq!(part2(&map).unwrap(), 9);
+    }
+}